@@ -0,0 +1,132 @@
+//! Aligning, distributing and fitting shapes, like the tools of vector editors.
+//!
+//! # Overview
+//!
+//! [`align`] lines a set of bounding boxes up along one of the edges or the center of the box around all of them,
+//! and [`distribute`] spaces them out evenly between the two outermost ones, which stay where they are.
+//! Both give back how far to move each box, in the same order, leaving it to the caller to move whatever the boxes belong to:
+//! shapes through [`LyonMeshBuilder::with_transformed`], baked meshes through [`BakedMesh::transform_vertices`],
+//! or shape entities through their transform.
+//!
+//! The bounds of a shape come from [`LyonShapeOutline::bounds`], the ones of a group of shapes from [`group_bounds`],
+//! and the ones of a plugin's shape entity from `LyonShape::bounds`.
+//!
+//! [`fit_to_rect`] scales a group of shapes up or down to fit in a rect without stretching them, such as for thumbnails of vector assets,
+//! giving the transform to add each of the shapes with. [`BakedMesh::fit_to_rect`] does the same to a mesh that was already built,
+//! whose bounds include the width of its strokes.
+//! Like bevy, the y axis goes up, so the top of a box is its largest y.
+//!
+//! [`LyonMeshBuilder::with_transformed`]: crate::mesh_builder::LyonMeshBuilder::with_transformed
+//! [`BakedMesh::transform_vertices`]: crate::baked::BakedMesh::transform_vertices
+//! [`BakedMesh::fit_to_rect`]: crate::baked::BakedMesh::fit_to_rect
+//! [`LyonShapeOutline::bounds`]: crate::shapes::LyonShapeOutline::bounds
+
+use super::math;
+use super::shapes::LyonShapeOutline;
+
+/// Where [`align`] lines boxes up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment
+{
+    Left,
+    /// The vertical line through the center.
+    Center,
+    Right,
+    Top,
+    /// The horizontal line through the center.
+    Middle,
+    Bottom,
+}
+
+/// The direction [`distribute`] spaces boxes out along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis
+{
+    Horizontal,
+    Vertical,
+}
+
+/// How far to move each box so they are all aligned with the box around all of them.
+pub fn align(bounds: &[math::Rect], alignment: Alignment) -> Vec<math::Vector>
+{
+    let all = match bounds.iter().copied().reduce(|all, rect| all.union(&rect))
+    {
+        Some(all) => all,
+        None => return vec![],
+    };
+
+    bounds.iter()
+        .map(|rect| match alignment
+        {
+            Alignment::Left => math::vector(all.min_x() - rect.min_x(), 0.0),
+            Alignment::Center => math::vector(all.center().x - rect.center().x, 0.0),
+            Alignment::Right => math::vector(all.max_x() - rect.max_x(), 0.0),
+            Alignment::Top => math::vector(0.0, all.max_y() - rect.max_y()),
+            Alignment::Middle => math::vector(0.0, all.center().y - rect.center().y),
+            Alignment::Bottom => math::vector(0.0, all.min_y() - rect.min_y()),
+        })
+        .collect()
+}
+
+/// How far to move each box so the gaps between them are all the same along an axis, the first and last boxes staying where they are.
+///
+/// Boxes are ordered by where they start along the axis, whatever their order in `bounds`.
+/// When they are too wide to fit between the outermost ones, the gaps become overlaps of the same size.
+pub fn distribute(bounds: &[math::Rect], axis: Axis) -> Vec<math::Vector>
+{
+    let start = |rect: &math::Rect| match axis { Axis::Horizontal => rect.min_x(), Axis::Vertical => rect.min_y() };
+    let length = |rect: &math::Rect| match axis { Axis::Horizontal => rect.width(), Axis::Vertical => rect.height() };
+
+    let mut offsets = vec![math::vector(0.0, 0.0); bounds.len()];
+    if bounds.len() < 3
+    {
+        return offsets;
+    }
+
+    let mut order: Vec<usize> = (0..bounds.len()).collect();
+    order.sort_by(|&a, &b| start(&bounds[a]).partial_cmp(&start(&bounds[b])).unwrap_or(std::cmp::Ordering::Equal));
+
+    let (first, last) = (&bounds[order[0]], &bounds[order[order.len() - 1]]);
+    let span = start(last) + length(last) - start(first);
+    let total: f32 = bounds.iter().map(length).sum();
+    let gap = (span - total) / (bounds.len() - 1) as f32;
+
+    let mut position = start(first);
+    for &i in &order
+    {
+        let offset = position - start(&bounds[i]);
+        offsets[i] = match axis { Axis::Horizontal => math::vector(offset, 0.0), Axis::Vertical => math::vector(0.0, offset) };
+        position += length(&bounds[i]) + gap;
+    }
+
+    offsets
+}
+
+/// The bounding box around the outlines of all the shapes, or `None` if none of them has any points.
+pub fn group_bounds<S>(shapes: impl IntoIterator<Item=S>, tolerance: f32) -> Option<math::Rect>
+where
+    S: LyonShapeOutline
+{
+    shapes.into_iter()
+        .filter_map(|shape| shape.bounds(tolerance))
+        .reduce(|all, bounds| all.union(&bounds))
+}
+
+/// The transform scaling `bounds` by the same factor along both axes and moving it so that it fits in `target`, centered in it.
+///
+/// Bounds without any width or height are only scaled to fit along the other axis, and points are only moved to the center.
+pub fn fit_to_rect(bounds: &math::Rect, target: &math::Rect) -> math::Transform
+{
+    let scale_x = if bounds.width() > 0.0 { Some(target.width() / bounds.width()) } else { None };
+    let scale_y = if bounds.height() > 0.0 { Some(target.height() / bounds.height()) } else { None };
+
+    let scale = match (scale_x, scale_y)
+    {
+        (Some(x), Some(y)) => x.min(y),
+        (Some(scale), None) | (None, Some(scale)) => scale,
+        (None, None) => 1.0,
+    };
+
+    let offset = target.center() - bounds.center().to_vector() * scale;
+    math::Transform::new(scale, 0.0, 0.0, scale, offset.x, offset.y)
+}
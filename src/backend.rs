@@ -0,0 +1,115 @@
+//! Conversion of finished geometry into the [`Mesh`] type of whichever version of [`bevy`] is enabled.
+//!
+//! Each supported version of bevy has a cargo feature (`bevy-0-2`, the default, and `bevy-0-5`),
+//! and exactly one of them must be enabled, unless the `headless` feature is enabled instead.
+//! Headless builds don't depend on bevy at all, and use the plain stand-ins for its mesh types defined here.
+
+#[cfg(not(feature = "headless"))]
+use std::borrow::Cow;
+
+#[cfg(not(feature = "headless"))]
+pub use bevy::render::{
+    mesh::{
+        VertexAttributeValues,
+        Mesh
+    },
+    pipeline::PrimitiveTopology,
+};
+
+/// The named vertex attributes of a mesh, in the form shared by every version of bevy.
+pub type MeshAttributes = Vec<(&'static str, VertexAttributeValues)>;
+
+/// Name of the vertex attribute holding vertex positions.
+pub const ATTRIBUTE_POSITION: &str = "Vertex_Position";
+/// Name of the vertex attribute holding vertex normals.
+pub const ATTRIBUTE_NORMAL: &str = "Vertex_Normal";
+/// Name of the vertex attribute holding vertex uvs.
+pub const ATTRIBUTE_UV: &str = "Vertex_Uv";
+
+/// Stand-in for bevy's primitive topology, used by headless builds.
+#[cfg(feature = "headless")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrimitiveTopology
+{
+    PointList,
+    LineList,
+    LineStrip,
+    TriangleList,
+    TriangleStrip,
+}
+
+/// Stand-in for bevy's vertex attribute values, used by headless builds.
+#[cfg(feature = "headless")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum VertexAttributeValues
+{
+    Float(Vec<f32>),
+    Float2(Vec<[f32; 2]>),
+    Float3(Vec<[f32; 3]>),
+    Float4(Vec<[f32; 4]>),
+}
+
+/// Stand-in for bevy's mesh used by headless builds, holding the finished geometry as plain vectors.
+#[cfg(feature = "headless")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mesh
+{
+    pub primitive_topology: PrimitiveTopology,
+    pub attributes: MeshAttributes,
+    pub indices: Option<Vec<u32>>,
+}
+
+#[cfg(feature = "headless")]
+impl Mesh
+{
+    /// Looks up the values of a vertex attribute by name.
+    pub fn attribute(&self, name: &str) -> Option<&VertexAttributeValues>
+    {
+        self.attributes.iter()
+            .find(|(attribute, _)| *attribute == name)
+            .map(|(_, values)| values)
+    }
+}
+
+/// Creates a mesh out of its vertex attributes and indices.
+#[cfg(feature = "headless")]
+pub fn mesh(topology: PrimitiveTopology, attributes: MeshAttributes, indices: Option<Vec<u32>>) -> Mesh
+{
+    Mesh {
+        primitive_topology: topology,
+        attributes,
+        indices,
+    }
+}
+
+/// Creates a mesh out of its vertex attributes and indices.
+#[cfg(feature = "bevy-0-2")]
+pub fn mesh(topology: PrimitiveTopology, attributes: MeshAttributes, indices: Option<Vec<u32>>) -> Mesh
+{
+    use bevy::render::mesh::VertexAttribute;
+
+    Mesh {
+        primitive_topology: topology,
+        attributes: attributes.into_iter()
+            .map(|(name, values)| VertexAttribute { name: Cow::Borrowed(name), values })
+            .collect(),
+        indices,
+    }
+}
+
+/// Creates a mesh out of its vertex attributes and indices.
+#[cfg(feature = "bevy-0-5")]
+pub fn mesh(topology: PrimitiveTopology, attributes: MeshAttributes, indices: Option<Vec<u32>>) -> Mesh
+{
+    use bevy::render::mesh::Indices;
+
+    let mut mesh = Mesh::new(topology);
+
+    for (name, values) in attributes
+    {
+        mesh.set_attribute(Cow::Borrowed(name), values);
+    }
+
+    mesh.set_indices(indices.map(Indices::U32));
+    mesh
+}
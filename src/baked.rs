@@ -0,0 +1,374 @@
+//! Pre-tessellated geometry that can be stored and loaded without tessellating again.
+//!
+//! # Overview
+//!
+//! A [`BakedMesh`] is made with [`LyonMeshBuilder::bake`] and turned back into a builder with `LyonMeshBuilder::from`,
+//! which is how heavy tessellation is moved out of the game and into an asset build step.
+//! With the `serialization` feature enabled, baked meshes can be written to and read from `.lyonmesh` files,
+//! which bevy can load directly as meshes through the [`LyonMeshLoader`](crate::loader::LyonMeshLoader).
+//!
+//! # The `.lyonmesh` format
+//!
+//! A `.lyonmesh` file starts with the 8 bytes `LYONMESH`, followed by the format version as a little-endian `u32`.
+//! The rest of the file is the [`BakedMesh`] itself, encoded with `bincode`.
+//!
+//! [`LyonMeshBuilder::bake`]: crate::mesh_builder::LyonMeshBuilder::bake
+
+use std::collections::HashMap;
+
+#[cfg(feature = "serialization")]
+use std::{
+    convert::TryInto,
+    error::Error,
+    fmt,
+};
+
+#[cfg(feature = "serialization")]
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::math;
+use super::align::fit_to_rect;
+
+use super::mesh_builder::{
+    BevyIndex,
+    BevyVertex,
+    ShapeRange,
+};
+
+use super::shapes::Contour;
+
+/// The finished geometry of a mesh builder, along with the ranges of the shapes that produced it.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct BakedMesh
+{
+    pub vertices: Vec<BevyVertex>,
+    pub indices: Vec<BevyIndex>,
+    pub shapes: Vec<ShapeRange>,
+    /// The 2d bounding box of all the vertices.
+    pub bounds: math::Rect,
+}
+
+/// The area of a mesh and how it is distributed, such as to set up a rigid body for a shape, see [`BakedMesh::mass_properties`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MassProperties
+{
+    pub area: f32,
+    /// The center of mass, for a uniform density.
+    pub centroid: math::Point,
+    /// The polar second moment of area around the centroid, which is the rotational inertia for a density of `1.0`.
+    pub inertia: f32,
+}
+
+impl MassProperties
+{
+    /// The mass of the shape for a uniform density.
+    pub fn mass(&self, density: f32) -> f32
+    {
+        self.area * density
+    }
+
+    /// The rotational inertia around the centroid for a uniform density.
+    pub fn angular_inertia(&self, density: f32) -> f32
+    {
+        self.inertia * density
+    }
+}
+
+impl BakedMesh
+{
+    /// Adds the geometry of another baked mesh after this one's, keeping the ranges of its shapes.
+    pub fn append(&mut self, other: BakedMesh)
+    {
+        let vertex_offset = self.vertices.len() as BevyIndex;
+        let index_offset = self.indices.len() as BevyIndex;
+
+        self.bounds = if self.vertices.is_empty() { other.bounds } else { self.bounds.union(&other.bounds) };
+
+        self.vertices.extend(other.vertices);
+        self.indices.extend(other.indices.into_iter().map(|index| index + vertex_offset));
+        self.shapes.extend(other.shapes.into_iter().map(|range| ShapeRange {
+            vertices: range.vertices.start + vertex_offset..range.vertices.end + vertex_offset,
+            indices: range.indices.start + index_offset..range.indices.end + index_offset,
+        }));
+    }
+
+    /// Modifies every vertex in place, such as to move the mesh, and updates the bounds to match.
+    pub fn transform_vertices(&mut self, transform: impl FnMut(&mut BevyVertex))
+    {
+        self.vertices.iter_mut().for_each(transform);
+        self.bounds = math::Rect::from_points(self.vertices.iter().map(|vertex| math::point(vertex.pos[0], vertex.pos[1])));
+    }
+
+    /// Scales the mesh by the same factor along both axes and moves it so that it fits in a rect, centered in it, see [`fit_to_rect`].
+    pub fn fit_to_rect(&mut self, target: &math::Rect)
+    {
+        let transform = fit_to_rect(&self.bounds, target);
+
+        self.transform_vertices(|vertex| {
+            let position = transform.transform_point(math::point(vertex.pos[0], vertex.pos[1]));
+            vertex.pos[0] = position.x;
+            vertex.pos[1] = position.y;
+        });
+    }
+
+    /// The triangles of the mesh, flattened onto the XY plane.
+    pub fn triangles(&self) -> impl Iterator<Item=[math::Point; 3]> + '_
+    {
+        let point = move |index: BevyIndex| {
+            let pos = self.vertices[index as usize].pos;
+            math::point(pos[0], pos[1])
+        };
+
+        self.indices.chunks_exact(3).map(move |triangle| [point(triangle[0]), point(triangle[1]), point(triangle[2])])
+    }
+
+    /// Computes the area, centroid and rotational inertia of the mesh's triangles, for a uniform density.
+    ///
+    /// Triangles are counted whichever way they are wound, so overlapping shapes count their shared area twice.
+    pub fn mass_properties(&self) -> MassProperties
+    {
+        // Working around the middle of the mesh keeps the squared distances small, which loses less precision
+        let origin = self.bounds.center();
+
+        let (mut area, mut moment, mut inertia) = (0.0, math::Vector::zero(), 0.0);
+
+        for [a, b, c] in self.triangles()
+        {
+            let (a, b, c) = (a - origin, b - origin, c - origin);
+            let triangle_area = (b - a).cross(c - a).abs() / 2.0;
+
+            area += triangle_area;
+            moment += (a + b + c) * (triangle_area / 3.0);
+            inertia += triangle_area / 6.0 * (a.square_length() + b.square_length() + c.square_length() + a.dot(b) + b.dot(c) + c.dot(a));
+        }
+
+        if area.is_nan() || area <= 0.0
+        {
+            return MassProperties { centroid: origin, ..Default::default() };
+        }
+
+        let centroid = moment / area;
+
+        // Parallel axis theorem, moving the inertia from the origin to the centroid
+        MassProperties {
+            area,
+            centroid: origin + centroid,
+            inertia: inertia - area * centroid.square_length(),
+        }
+    }
+
+    /// Merges the triangles of the mesh back into convex polygons, for algorithms that work better on a few convex pieces than on many triangles.
+    ///
+    /// Neighbouring triangles are merged for as long as the result stays convex, which gives at most four times as many pieces as the
+    /// fewest possible. Only triangles sharing vertices are merged, which is the case within a single fill shape.
+    /// Every piece is wound counter-clockwise.
+    pub fn convex_pieces(&self) -> Vec<Contour>
+    {
+        let point = |index: BevyIndex| {
+            let pos = self.vertices[index as usize].pos;
+            math::point(pos[0], pos[1])
+        };
+
+        let turn = |a: BevyIndex, b: BevyIndex, c: BevyIndex| (point(b) - point(a)).cross(point(c) - point(b));
+
+        let mut pieces: Vec<Vec<BevyIndex>> = self.indices.chunks_exact(3)
+            .map(|triangle| match turn(triangle[0], triangle[1], triangle[2]) < 0.0
+            {
+                true => vec![triangle[0], triangle[2], triangle[1]],
+                false => triangle.to_vec(),
+            })
+            .collect();
+
+        // Every directed edge, along with the triangle it belongs to
+        let edges: HashMap<(BevyIndex, BevyIndex), usize> = pieces.iter()
+            .enumerate()
+            .flat_map(|(i, piece)| (0..3).map(move |j| ((piece[j], piece[(j + 1) % 3]), i)))
+            .collect();
+
+        // Which piece every triangle was merged into, followed until a piece that is still its own
+        let mut merged_into: Vec<usize> = (0..pieces.len()).collect();
+        let find = |merged_into: &Vec<usize>, mut piece: usize| {
+            while merged_into[piece] != piece
+            {
+                piece = merged_into[piece];
+            }
+            piece
+        };
+
+        // Every shared edge once, sorted so the pieces don't depend on the order of the map
+        let mut shared: Vec<_> = edges.keys()
+            .filter(|&&(a, b)| a < b && edges.contains_key(&(b, a)))
+            .copied()
+            .collect();
+        shared.sort_unstable();
+
+        for (a, b) in shared
+        {
+            let (first, second) = (find(&merged_into, edges[&(a, b)]), find(&merged_into, edges[&(b, a)]));
+            if first == second
+            {
+                continue;
+            }
+
+            // Both pieces are rotated so the shared edge closes them, then the second one's other vertices are spliced in
+            let rotated = |piece: &[BevyIndex], start: BevyIndex| {
+                let at = piece.iter().position(|&index| index == start).unwrap();
+                piece[at..].iter().chain(&piece[..at]).copied().collect::<Vec<_>>()
+            };
+
+            let mut merged = rotated(&pieces[first], b);
+            let other = rotated(&pieces[second], a);
+            merged.extend_from_slice(&other[1..other.len() - 1]);
+
+            let count = merged.len();
+            let convex_at = |at: usize| turn(merged[(at + count - 1) % count], merged[at], merged[(at + 1) % count]) >= -f32::EPSILON;
+
+            // Only the corners at the ends of the removed edge can stop being convex
+            if convex_at(0) && convex_at(count - other.len() + 1)
+            {
+                pieces[first] = merged;
+                pieces[second].clear();
+                merged_into[second] = first;
+            }
+        }
+
+        pieces.into_iter()
+            .filter(|piece| !piece.is_empty())
+            .map(|piece| Contour { points: piece.into_iter().map(point).collect(), is_closed: true })
+            .collect()
+    }
+
+    /// Picks `count` points spread uniformly over the area of the mesh, such as to spawn particles or foliage within a shape.
+    ///
+    /// `random` has to return numbers uniformly distributed between `0.0` and `1.0`, so any random number generator can be used.
+    /// Gives no points at all if the mesh has no area.
+    pub fn sample_points(&self, count: usize, mut random: impl FnMut() -> f32) -> Vec<math::Point>
+    {
+        // The running total of the areas, so a triangle is picked with a chance proportional to its area
+        let mut total = 0.0;
+        let triangles: Vec<_> = self.triangles()
+            .map(|[a, b, c]| {
+                total += (b - a).cross(c - a).abs() / 2.0;
+                ([a, b, c], total)
+            })
+            .collect();
+
+        if total.is_nan() || total <= 0.0
+        {
+            return vec![];
+        }
+
+        (0..count)
+            .map(|_| {
+                let target = random() * total;
+                let picked = triangles.iter()
+                    .position(|&(_, area)| area > target)
+                    .unwrap_or(triangles.len() - 1);
+                let [a, b, c] = triangles[picked].0;
+
+                // Points past the diagonal of the parallelogram are folded back into the triangle
+                let (mut u, mut v) = (random(), random());
+                if u + v > 1.0
+                {
+                    u = 1.0 - u;
+                    v = 1.0 - v;
+                }
+
+                a + (b - a) * u + (c - a) * v
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl BakedMesh
+{
+    /// The bytes every `.lyonmesh` file starts with.
+    pub const MAGIC: &'static [u8; 8] = b"LYONMESH";
+
+    /// The version of the `.lyonmesh` format written by [`BakedMesh::to_bytes`].
+    pub const FORMAT_VERSION: u32 = 2;
+
+    /// Writes the baked mesh as the contents of a `.lyonmesh` file.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, BakedMeshError>
+    {
+        let mut bytes = Self::MAGIC.to_vec();
+        bytes.extend_from_slice(&Self::FORMAT_VERSION.to_le_bytes());
+        bincode::serialize_into(&mut bytes, self)?;
+
+        Ok(bytes)
+    }
+
+    /// Reads a baked mesh back from the contents of a `.lyonmesh` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BakedMeshError>
+    {
+        let header_len = Self::MAGIC.len() + 4;
+
+        if bytes.len() < header_len || &bytes[..Self::MAGIC.len()] != Self::MAGIC
+        {
+            return Err(BakedMeshError::InvalidHeader);
+        }
+
+        let version = u32::from_le_bytes(bytes[Self::MAGIC.len()..header_len].try_into().unwrap());
+
+        if version != Self::FORMAT_VERSION
+        {
+            return Err(BakedMeshError::UnsupportedVersion(version));
+        }
+
+        Ok(bincode::deserialize(&bytes[header_len..])?)
+    }
+}
+
+/// The ways reading or writing a `.lyonmesh` file can fail.
+#[cfg(feature = "serialization")]
+#[derive(Debug)]
+pub enum BakedMeshError
+{
+    /// The file doesn't start with [`BakedMesh::MAGIC`], so it isn't a `.lyonmesh` file.
+    InvalidHeader,
+    /// The file was written with a version of the format this version of the crate can't read.
+    UnsupportedVersion(u32),
+    /// The baked mesh itself couldn't be encoded or decoded.
+    Encoding(bincode::Error),
+}
+
+#[cfg(feature = "serialization")]
+impl fmt::Display for BakedMeshError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self
+        {
+            BakedMeshError::InvalidHeader => write!(f, "not a .lyonmesh file"),
+            BakedMeshError::UnsupportedVersion(version) => write!(f, "unsupported .lyonmesh format version {}", version),
+            BakedMeshError::Encoding(error) => write!(f, "invalid .lyonmesh contents: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Error for BakedMeshError
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)>
+    {
+        match self
+        {
+            BakedMeshError::Encoding(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl From<bincode::Error> for BakedMeshError
+{
+    fn from(error: bincode::Error) -> Self
+    {
+        BakedMeshError::Encoding(error)
+    }
+}
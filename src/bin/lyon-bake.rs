@@ -0,0 +1,108 @@
+//! Offline tessellation of recorded shapes into baked mesh files.
+//!
+//! Usage: `lyon-bake <INPUT> <OUTPUT.lyonmesh>`
+//!
+//! The input is a list of [`ShapeCommand`]'s in binary form, such as the commands of a [`RecordingBuilder`](bevy_lyon::RecordingBuilder)
+//! written with `bincode`. The output is the [`BakedMesh`](bevy_lyon::BakedMesh) of all of those shapes, as a `.lyonmesh` file.
+//!
+//! With the `svg` feature, inputs ending in `.svg` are read as SVG files instead, filling the path data of every one of their `<path>` elements,
+//! flipped so that they are the right way up in bevy. Everything else in the file, such as its transforms and styles, is left out.
+
+use std::{
+    env,
+    error::Error,
+    fs,
+    process,
+};
+
+use bevy_lyon::{
+    LyonMeshBuilder,
+    ShapeCommand,
+};
+
+fn main()
+{
+    let args: Vec<_> = env::args().skip(1).collect();
+
+    if args.len() != 2
+    {
+        eprintln!("usage: lyon-bake <INPUT> <OUTPUT>");
+        process::exit(2);
+    }
+
+    if let Err(error) = bake(&args[0], &args[1])
+    {
+        eprintln!("lyon-bake: {}", error);
+        process::exit(1);
+    }
+}
+
+/// Tessellates the shapes recorded in the input file and writes the baked mesh to the output file.
+fn bake(input: &str, output: &str) -> Result<(), Box<dyn Error>>
+{
+    #[cfg(feature = "svg")]
+    {
+        if input.ends_with(".svg")
+        {
+            let baked = bake_svg(&fs::read_to_string(input)?)?;
+
+            fs::write(output, baked.to_bytes()?)?;
+            return Ok(());
+        }
+    }
+
+    let commands: Vec<ShapeCommand> = bincode::deserialize(&fs::read(input)?)?;
+
+    let baked = commands.into_iter()
+        .fold(LyonMeshBuilder::new(), LyonMeshBuilder::with)
+        .bake();
+
+    fs::write(output, baked.to_bytes()?)?;
+    Ok(())
+}
+
+/// Fills the path data of every `<path>` element of an SVG file.
+#[cfg(feature = "svg")]
+fn bake_svg(svg: &str) -> Result<bevy_lyon::BakedMesh, Box<dyn Error>>
+{
+    use bevy_lyon::{
+        math,
+        shapes::SvgPathShape,
+    };
+
+    let options = lyon::tessellation::FillOptions::DEFAULT;
+    // SVG's Y axis points down while bevy's points up
+    let flip = math::Transform::scale(1.0, -1.0);
+    let mut builder = LyonMeshBuilder::new();
+
+    for element in svg.split("<path").skip(1)
+    {
+        let element = &element[..element.find('>').unwrap_or(element.len())];
+
+        if let Some(data) = attribute(element, "d")
+        {
+            let path = SvgPathShape::parse(data).map_err(|error| format!("invalid path data {:?}: {:?}", data, error))?;
+            builder = builder.with_transformed(path.fill(&options), &flip);
+        }
+    }
+
+    Ok(builder.bake())
+}
+
+/// Finds the value of an attribute among the attributes of an element, quoted either way.
+#[cfg(feature = "svg")]
+fn attribute<'a>(element: &'a str, name: &str) -> Option<&'a str>
+{
+    let pattern = format!("{}=", name);
+
+    element.match_indices(&pattern)
+        // Skips the attributes whose names only end with the name, such as `id` when looking for `d`
+        .find(|&(start, _)| element[..start].ends_with(char::is_whitespace))
+        .and_then(|(start, _)| {
+            let value = &element[start + pattern.len()..];
+            let quote = value.chars().next().filter(|&quote| quote == '"' || quote == '\'')?;
+            let value = &value[1..];
+
+            value.find(quote).map(|end| &value[..end])
+        })
+}
@@ -0,0 +1,155 @@
+//! Confetti, bursts of small randomly scattered shapes, such as for celebrations and debris.
+//!
+//! # Overview
+//!
+//! A [`Confetti`] burst scatters small rects, triangles and circles within a circle around its center,
+//! each with a random size, rotation and color from its palette. All of the pieces are built into a single mesh with their colors in its vertices.
+//!
+//! The burst is random but seeded, so the same seed always gives the same pieces, such as for replays or for a burst shared over the network.
+//! When [`Confetti::shape_indices`] is set, every vertex also knows which piece it belongs to
+//! through [`LyonMeshBuilder::ATTRIBUTE_SHAPE_INDEX`], so a shader can fling each piece on its own path.
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::{
+    LyonMeshBuilder,
+    VertexLayout,
+};
+use super::recording::ShapeCommand;
+use super::shapes::{
+    FillCircle,
+    FillRegularPolygon,
+    FillRotatedRect,
+};
+
+/// The shapes the pieces of [`Confetti`] can have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfettiKind
+{
+    /// A strip half as wide as it is long.
+    Rect,
+    Triangle,
+    Circle,
+}
+
+/// A burst of confetti, see the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct Confetti
+{
+    pub center: math::Point,
+    #[default = 50]
+    pub count: usize,
+    /// The radius of the circle the pieces are scattered in.
+    #[default = 100.0]
+    pub spread: f32,
+    #[default = 3.0]
+    pub min_size: f32,
+    #[default = 8.0]
+    pub max_size: f32,
+    /// The shapes the pieces are picked from, equally often.
+    #[default(vec![ConfettiKind::Rect, ConfettiKind::Triangle, ConfettiKind::Circle])]
+    pub kinds: Vec<ConfettiKind>,
+    /// The colors the pieces are picked from, equally often.
+    #[default(vec![
+        [0.95, 0.3, 0.35, 1.0],
+        [1.0, 0.8, 0.2, 1.0],
+        [0.3, 0.8, 0.45, 1.0],
+        [0.3, 0.6, 1.0, 1.0],
+        [0.75, 0.4, 0.95, 1.0],
+    ])]
+    pub colors: Vec<[f32; 4]>,
+    pub seed: u64,
+    /// Whether the mesh has a [`LyonMeshBuilder::ATTRIBUTE_SHAPE_INDEX`] attribute numbering the pieces.
+    pub shape_indices: bool,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Confetti
+{
+    /// The pieces of the burst with their colors, the same ones every time for the same seed.
+    pub fn pieces(&self) -> Vec<(ShapeCommand, [f32; 4])>
+    {
+        if self.kinds.is_empty() || self.colors.is_empty()
+        {
+            return vec![];
+        }
+
+        let options = tess::FillOptions::tolerance(self.tolerance);
+        let mut random = SplitMix64(self.seed);
+
+        (0..self.count)
+            .map(|_| {
+                // The square root spreads the pieces evenly over the circle instead of bunching them at its center
+                let distance = self.spread * random.next_f32().sqrt();
+                let direction = math::Angle::radians(random.next_f32() * std::f32::consts::PI * 2.0);
+                let center = self.center + math::vector(direction.radians.cos(), direction.radians.sin()) * distance;
+
+                let size = self.min_size + (self.max_size - self.min_size) * random.next_f32();
+                let rotation = math::Angle::radians(random.next_f32() * std::f32::consts::PI * 2.0);
+                let kind = self.kinds[random.next_index(self.kinds.len())];
+                let color = self.colors[random.next_index(self.colors.len())];
+
+                let shape = match kind
+                {
+                    ConfettiKind::Rect =>
+                        FillRotatedRect { center, size: math::size(size, size / 2.0), angle: rotation, options: &options }.into(),
+                    ConfettiKind::Triangle =>
+                        FillRegularPolygon { center, radius: size / 2.0, sides: 3, rotation, options: &options }.into(),
+                    ConfettiKind::Circle =>
+                        FillCircle { center, radius: size / 2.0, options: &options }.into(),
+                };
+
+                (shape, color)
+            })
+            .collect()
+    }
+
+    /// Tessellates the pieces into a mesh builder, each as a shape of its own.
+    pub fn builder(&self) -> LyonMeshBuilder
+    {
+        let builder = LyonMeshBuilder::new().with_vertex_layout(VertexLayout { shape_indices: self.shape_indices, ..Default::default() });
+
+        self.pieces()
+            .into_iter()
+            .fold(builder, |builder, (shape, color)| builder.with_colored(shape, color))
+    }
+
+    /// Builds the mesh of the pieces.
+    pub fn build(&self) -> Mesh
+    {
+        self.builder().build()
+    }
+}
+
+/// Internal utility type that generates random numbers from a seed with the SplitMix64 algorithm, which is plenty for scattering pieces.
+struct SplitMix64(u64);
+
+impl SplitMix64
+{
+    fn next_u64(&mut self) -> u64
+    {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number between `0.0` included and `1.0` excluded.
+    fn next_f32(&mut self) -> f32
+    {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// An index below `len`.
+    fn next_index(&mut self, len: usize) -> usize
+    {
+        (self.next_u64() % len as u64) as usize
+    }
+}
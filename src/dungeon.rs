@@ -0,0 +1,199 @@
+//! Dungeon maps, rooms joined by corridors, such as for procedurally generated levels.
+//!
+//! # Overview
+//!
+//! A [`Dungeon`] is made from the outlines of its rooms and the center lines of its corridors.
+//! Its floor is the union of all of them, filled as a single shape so that overlapping rooms and corridors don't overlap in the mesh.
+//! Its walls follow the outline of that union, offset outwards so that they run along the outside of the floor without covering it.
+//!
+//! Corridors are made of a rect along each of their segments, extended by half their width at either end,
+//! which joins segments meeting at right angles seamlessly and covers most of the joint of other turns.
+
+use smart_default::*;
+
+use lyon::{
+    path::{
+        self,
+        Polygon,
+    },
+    tessellation as tess,
+};
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::{
+    BevyBuffersBuilder,
+    LyonMeshBuilder,
+};
+use super::shapes::{
+    counter_clockwise,
+    union_contours,
+    Contour,
+    LyonShapeBuilder,
+    LyonShapeOutline,
+    StrokePolyline,
+};
+
+/// A corridor of a dungeon, `width` wide along a polyline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Corridor
+{
+    pub points: Vec<math::Point>,
+    pub width: f32,
+}
+
+/// A dungeon, see the [module level documentation](self). Building it as a shape fills its floor.
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct Dungeon
+{
+    /// The outlines of the rooms, which are always treated as closed.
+    pub rooms: Vec<Contour>,
+    pub corridors: Vec<Corridor>,
+    #[default = 4.0]
+    pub wall_width: f32,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Dungeon
+{
+    /// Adds a room in the shape of a shape's outline, such as a rect or a polygon, flattened at the tolerance of the dungeon.
+    pub fn with_room(mut self, shape: impl LyonShapeOutline) -> Self
+    {
+        self.rooms.extend(shape.outline(self.tolerance));
+        self
+    }
+
+    /// Adds a corridor along a polyline.
+    pub fn with_corridor(mut self, points: Vec<math::Point>, width: f32) -> Self
+    {
+        self.corridors.push(Corridor { points, width });
+        self
+    }
+
+    /// The outline of the floor, counter-clockwise around the outside and clockwise around any enclosed gaps.
+    pub fn floor_outline(&self) -> Vec<Contour>
+    {
+        union_contours(self.floor_polygons())
+    }
+
+    /// The center lines of the walls, which are the outline of the floor moved outwards by half the width of the walls.
+    pub fn wall_outlines(&self) -> Vec<Contour>
+    {
+        let offset = self.wall_width / 2.0;
+
+        self.floor_outline()
+            .into_iter()
+            .map(|contour| {
+                let count = contour.points.len();
+                let points = (0..count)
+                    .map(|i| {
+                        let (previous, point, next) = (contour.points[(i + count - 1) % count], contour.points[i], contour.points[(i + 1) % count]);
+                        let outwards = |direction: math::Vector| direction.try_normalize().map_or(math::vector(0.0, 0.0), |d| math::vector(d.y, -d.x));
+                        let (before, after) = (outwards(point - previous), outwards(next - point));
+
+                        // The corner moves along the bisector of its edges' normals, far enough for both edges to move by the offset
+                        let bisector = (before + after).try_normalize().unwrap_or(before);
+                        point + bisector * (offset / bisector.dot(before).max(0.25))
+                    })
+                    .collect();
+
+                Contour { points, is_closed: true }
+            })
+            .collect()
+    }
+
+    /// Tessellates the floor into a mesh builder, ready to be built.
+    pub fn floor_builder(&self) -> LyonMeshBuilder
+    {
+        LyonMeshBuilder::new().with(self.clone())
+    }
+
+    /// Tessellates the walls into a mesh builder, with each wall outline as a shape of its own.
+    pub fn walls_builder(&self) -> LyonMeshBuilder
+    {
+        let options = tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.wall_width);
+
+        self.wall_outlines().into_iter().fold(LyonMeshBuilder::new(), |builder, contour| {
+            builder.with(StrokePolyline { points: contour.points, is_closed: true, options: &options })
+        })
+    }
+
+    /// Builds the mesh of the floor.
+    pub fn build_floor(&self) -> Mesh
+    {
+        self.floor_builder().build()
+    }
+
+    /// Builds the mesh of the walls.
+    pub fn build_walls(&self) -> Mesh
+    {
+        self.walls_builder().build()
+    }
+
+    /// Internal utility function that gives the fill options of the floor, which merges overlapping rooms and corridors.
+    fn floor_options(&self) -> tess::FillOptions
+    {
+        tess::FillOptions::tolerance(self.tolerance).with_fill_rule(tess::FillRule::NonZero)
+    }
+
+    /// Internal utility function that gives the polygon of every room and corridor segment, all wound counter-clockwise.
+    fn floor_polygons(&self) -> Vec<Vec<math::Point>>
+    {
+        let mut polygons: Vec<Vec<math::Point>> = self.rooms.iter()
+            .map(|room| counter_clockwise(&room.points))
+            .collect();
+
+        for corridor in &self.corridors
+        {
+            for segment in corridor.points.windows(2)
+            {
+                let direction = match (segment[1] - segment[0]).try_normalize()
+                {
+                    Some(direction) => direction * (corridor.width / 2.0),
+                    None => continue,
+                };
+                let side = math::vector(-direction.y, direction.x);
+                let (start, end) = (segment[0] - direction, segment[1] + direction);
+
+                polygons.push(vec![start - side, end - side, end + side, start + side]);
+            }
+        }
+
+        polygons
+    }
+
+    /// Internal utility function that builds the path of every room and corridor segment.
+    fn floor_path(&self) -> path::Path
+    {
+        let mut builder = path::Path::builder();
+
+        for polygon in self.floor_polygons()
+        {
+            builder.add_polygon(Polygon { points: &polygon, closed: true });
+        }
+
+        builder.build()
+    }
+}
+
+impl LyonShapeBuilder for Dungeon
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_path(&self.floor_path(), &self.floor_options(), builder)
+    }
+}
+
+impl LyonShapeOutline for Dungeon
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        self.floor_outline()
+    }
+}
@@ -0,0 +1,520 @@
+//! Freehand drawing, turning the samples of a pointer into a smooth stroke as it is drawn.
+//!
+//! # Overview
+//!
+//! A [`FreehandStroke`] takes the samples of a pointer one at a time, each with its pressure when the device reports one.
+//! Samples too close to the last one are dropped as jitter, and the rest are smoothed then resampled at an even spacing along the way,
+//! so the stroke is as dense where the pointer moved fast as where it moved slowly.
+//!
+//! Every resampled point has its own width from the pressure, and is drawn as a round dab joined to the previous one.
+//! The points only ever get added at the end of the stroke, so [`FreehandStroke::extend`] only tessellates the new ones into a builder
+//! that already has the others, instead of tessellating the whole stroke again on every sample.
+//!
+//! A [`FreehandCanvas`] keeps the strokes of a drawing, each tessellated into a builder of its own.
+//! Its eraser sweeps a circle from where it was to where it is, and cuts it out of the dabs and joins it goes over like [`FillSubtracted`] does,
+//! splitting the strokes around the points it covers whole. Only the dabs and joins it went over are tessellated again,
+//! the others being copied from the tessellation of their stroke with [`LyonMeshBuilder::with_base_shapes`].
+//!
+//! With the `bevy-0-5` feature, the plugin draws the stroke of entities with a [`FreehandDrawing`] component from mouse input.
+//!
+//! [`FillSubtracted`]: crate::shapes::FillSubtracted
+//! [`FreehandDrawing`]: crate::plugin::FreehandDrawing
+
+use smart_default::*;
+
+use lyon::{
+    geom::LineSegment,
+    tessellation as tess,
+};
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::LyonMeshBuilder;
+use super::recording::ShapeCommand;
+use super::shapes::{
+    arc_points,
+    union_contours,
+    Contour,
+    FillCircle,
+    FillQuad,
+    LyonShapeOutline,
+};
+
+/// A resampled point of a [`FreehandStroke`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FreehandPoint
+{
+    pub position: math::Point,
+    pub width: f32,
+}
+
+/// A stroke drawn freehand, see the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct FreehandStroke
+{
+    /// How far a sample has to be from the last sample kept to be kept too, anything closer being jitter.
+    #[default = 1.0]
+    pub min_distance: f32,
+    /// How much of the previous position every sample keeps, from `0.0` for none to just below `1.0` for a lot.
+    #[default = 0.5]
+    pub smoothing: f32,
+    /// The distance between the resampled points.
+    #[default = 2.0]
+    pub spacing: f32,
+    /// The width of the stroke at full pressure.
+    #[default = 4.0]
+    pub width: f32,
+    /// How much the pressure thins the stroke, from `0.0` for not at all to `1.0` for down to nothing without pressure.
+    #[default = 0.8]
+    pub pressure_influence: f32,
+    #[default([0.0, 0.0, 0.0, 1.0])]
+    pub color: [f32; 4],
+    #[default = 0.1]
+    pub tolerance: f32,
+    points: Vec<FreehandPoint>,
+    /// The polygons of the sweeps of the eraser cut out of the stroke.
+    cuts: Vec<Vec<math::Point>>,
+    /// The last sample kept, with its pressure.
+    last_sample: Option<(math::Point, f32)>,
+    /// The smoothed position and pressure the next sample moves on from.
+    smoothed: Option<(math::Point, f32)>,
+    /// How far the smoothed position went since the last resampled point.
+    carried: f32,
+}
+
+impl FreehandStroke
+{
+    /// The resampled points of the stroke so far.
+    pub fn points(&self) -> &[FreehandPoint]
+    {
+        &self.points
+    }
+
+    /// Whether a sample is far enough from the last sample kept to be kept too.
+    pub fn accepts(&self, position: math::Point) -> bool
+    {
+        self.last_sample.map_or(true, |(last, _)| (position - last).length() >= self.min_distance)
+    }
+
+    /// Adds a sample of the pointer, with its pressure between `0.0` and `1.0` if the device has one,
+    /// telling how many points it added at the end of the stroke.
+    pub fn push(&mut self, position: math::Point, pressure: Option<f32>) -> usize
+    {
+        if !self.accepts(position)
+        {
+            return 0;
+        }
+
+        let pressure = pressure.unwrap_or(1.0).max(0.0).min(1.0);
+        self.last_sample = Some((position, pressure));
+
+        let (from, from_pressure) = match self.smoothed
+        {
+            Some(smoothed) => smoothed,
+            None =>
+            {
+                self.smoothed = Some((position, pressure));
+                self.points.push(self.point(position, pressure));
+                return 1;
+            }
+        };
+
+        let keep = self.smoothing.max(0.0).min(0.99);
+        let to = from.lerp(position, 1.0 - keep);
+        let to_pressure = from_pressure + (pressure - from_pressure) * (1.0 - keep);
+        self.smoothed = Some((to, to_pressure));
+
+        // Walks the smoothed segment, leaving a point every time another spacing is covered
+        let length = (to - from).length();
+        let spacing = self.spacing.max(0.01);
+        let count = self.points.len();
+        let mut along = spacing - self.carried;
+
+        while along <= length
+        {
+            let t = along / length;
+            self.points.push(self.point(from.lerp(to, t), from_pressure + (to_pressure - from_pressure) * t));
+            along += spacing;
+        }
+
+        self.carried = length - (along - spacing);
+        self.points.len() - count
+    }
+
+    /// Ends the stroke at the last sample kept, which the smoothed points lag behind, telling how many points it added.
+    pub fn finish(&mut self) -> usize
+    {
+        match self.last_sample
+        {
+            Some((position, pressure)) if self.points.last().is_some_and(|last| last.position != position) =>
+            {
+                self.points.push(self.point(position, pressure));
+                self.smoothed = Some((position, pressure));
+                self.carried = 0.0;
+                1
+            }
+            _ => 0,
+        }
+    }
+
+    /// Drops every point and sample, to start a new stroke.
+    pub fn clear(&mut self)
+    {
+        self.points.clear();
+        self.cuts.clear();
+        self.last_sample = None;
+        self.smoothed = None;
+        self.carried = 0.0;
+    }
+
+    /// Sweeps an eraser of `radius` from `from` to `to`, giving back what is left of the stroke, or `None` if it didn't touch the stroke.
+    ///
+    /// The circle of the eraser is cut out of the dabs and joins it touches, so the stroke ends exactly where the eraser went.
+    /// The points it covers whole, along with their joins, are dropped, splitting the stroke into a piece on either side of each gap.
+    pub fn erase(&self, from: math::Point, to: math::Point, radius: f32) -> Option<Vec<FreehandStroke>>
+    {
+        self.erase_pieces(&Sweep::new(from, to, radius, self.tolerance))
+            .map(|pieces| pieces.into_iter().map(|(_, piece)| piece).collect())
+    }
+
+    /// The shapes of the points from `start` on, each a dab with the join from the previous point under it,
+    /// with the eraser cut out of the ones it went over.
+    pub fn shapes_from(&self, start: usize) -> Vec<ShapeCommand>
+    {
+        (start..self.points.len())
+            .flat_map(|i| self.point_shapes(i))
+            .collect()
+    }
+
+    /// Tessellates the points from `start` on into a builder that has the ones before, with the color of the stroke.
+    pub fn extend(&self, builder: LyonMeshBuilder, start: usize) -> LyonMeshBuilder
+    {
+        self.shapes_from(start)
+            .into_iter()
+            .fold(builder, |builder, shape| builder.with_colored(shape, self.color))
+    }
+
+    /// Tessellates the whole stroke into a mesh builder.
+    pub fn builder(&self) -> LyonMeshBuilder
+    {
+        self.extend(LyonMeshBuilder::new(), 0)
+    }
+
+    /// Builds the mesh of the whole stroke.
+    pub fn build(&self) -> Mesh
+    {
+        self.builder().build()
+    }
+
+    /// Internal utility method that splits what is left of the stroke after a sweep of the eraser into pieces,
+    /// each with the index of its first point in the stroke.
+    fn erase_pieces(&self, sweep: &Sweep) -> Option<Vec<(usize, FreehandStroke)>>
+    {
+        let count = self.points.len();
+        let touched = (0..count).any(|i| sweep.touches_dab(&self.points[i]) || self.join(i).is_some() && sweep.touches_join(&self.points[i - 1], &self.points[i]));
+
+        if !touched
+        {
+            return None;
+        }
+
+        // Points whose joins with both of their neighbours would be lost by splitting around them are kept, even if their dab is covered
+        let join_covered = |i: usize| self.join(i).into_iter().flatten().all(|corner| sweep.covers(corner, 0.0));
+        let dropped: Vec<bool> = (0..count)
+            .map(|i| {
+                sweep.covers(self.points[i].position, self.points[i].width / 2.0)
+                    && join_covered(i)
+                    && (i + 1 == count || join_covered(i + 1))
+            })
+            .collect();
+
+        let mut pieces = vec![];
+        let mut start = 0;
+
+        for end in (0..=count).filter(|&i| i == count || dropped[i])
+        {
+            if end > start
+            {
+                pieces.push((start, self.piece(&self.points[start..end], &sweep.polygon)));
+            }
+
+            start = end + 1;
+        }
+
+        Some(pieces)
+    }
+
+    /// Internal utility method that makes a finished stroke of the same style out of some of the points,
+    /// keeping the cuts of the eraser that reach them.
+    fn piece(&self, points: &[FreehandPoint], cut: &[math::Point]) -> FreehandStroke
+    {
+        let reach = points.iter().fold(0.0, |reach: f32, point| reach.max(point.width / 2.0));
+        let bounds = math::Rect::from_points(points.iter().map(|point| point.position)).inflate(reach, reach);
+
+        FreehandStroke {
+            min_distance: self.min_distance,
+            smoothing: self.smoothing,
+            spacing: self.spacing,
+            width: self.width,
+            pressure_influence: self.pressure_influence,
+            color: self.color,
+            tolerance: self.tolerance,
+            points: points.to_vec(),
+            cuts: self.cuts.iter()
+                .map(Vec::as_slice)
+                .chain(Some(cut))
+                .filter(|cut| math::Rect::from_points(cut.iter()).intersects(&bounds))
+                .map(<[math::Point]>::to_vec)
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Internal utility method that gives the shapes of a point, the join from the previous point if there is one then its dab.
+    fn point_shapes(&self, i: usize) -> Vec<ShapeCommand>
+    {
+        self.join(i)
+            .map(|corners| self.join_shape(corners))
+            .into_iter()
+            .chain(Some(self.dab_shape(&self.points[i])))
+            .collect()
+    }
+
+    /// Internal utility method that gives the shape of a join, with the eraser cut out of it.
+    fn join_shape(&self, corners: [math::Point; 4]) -> ShapeCommand
+    {
+        self.cut(FillQuad { points: corners, options: &tess::FillOptions::tolerance(self.tolerance) }.into())
+    }
+
+    /// Internal utility method that gives the shape of the dab of a point, with the eraser cut out of it.
+    fn dab_shape(&self, point: &FreehandPoint) -> ShapeCommand
+    {
+        self.cut(FillCircle { center: point.position, radius: point.width / 2.0, options: &tess::FillOptions::tolerance(self.tolerance) }.into())
+    }
+
+    /// Internal utility method that gives the corners of the join from the previous point to a point, if they aren't at the same position.
+    fn join(&self, i: usize) -> Option<[math::Point; 4]>
+    {
+        let point = self.points[i];
+
+        i.checked_sub(1)
+            .map(|previous| self.points[previous])
+            .filter(|previous| previous.position != point.position)
+            .map(|previous| {
+                let normal = (point.position - previous.position).normalize();
+                let normal = math::vector(-normal.y, normal.x);
+
+                [
+                    previous.position + normal * previous.width / 2.0,
+                    point.position + normal * point.width / 2.0,
+                    point.position - normal * point.width / 2.0,
+                    previous.position - normal * previous.width / 2.0,
+                ]
+            })
+    }
+
+    /// Internal utility method that subtracts the cuts of the eraser that reach a shape from it.
+    fn cut(&self, shape: ShapeCommand) -> ShapeCommand
+    {
+        let points: Vec<_> = shape.outline(self.tolerance).into_iter().flat_map(|contour| contour.points).collect();
+        if points.is_empty()
+        {
+            return shape;
+        }
+
+        let bounds = math::Rect::from_points(points);
+        let cuts: Vec<_> = self.cuts.iter()
+            .filter(|cut| math::Rect::from_points(cut.iter()).intersects(&bounds))
+            .collect();
+
+        if cuts.is_empty()
+        {
+            return shape;
+        }
+
+        // Overlapping cuts would fill each other back in, so they are cut as the contours of their union, one after the other
+        union_contours(cuts).into_iter().fold(shape, |shape, contour| ShapeCommand::FillSubtracted {
+            base: Box::new(shape),
+            cutter: Box::new(ShapeCommand::FillPolyline { points: contour.points, options: tess::FillOptions::DEFAULT }),
+            options: tess::FillOptions::tolerance(self.tolerance),
+        })
+    }
+
+    /// Internal utility method that makes a point with the width of its pressure.
+    fn point(&self, position: math::Point, pressure: f32) -> FreehandPoint
+    {
+        FreehandPoint { position, width: self.width * (1.0 - self.pressure_influence * (1.0 - pressure)) }
+    }
+}
+
+/// The circle of an eraser swept from one position to the next, with its outline as a polygon.
+struct Sweep
+{
+    segment: Contour,
+    radius: f32,
+    polygon: Vec<math::Point>,
+}
+
+impl Sweep
+{
+    fn new(from: math::Point, to: math::Point, radius: f32, tolerance: f32) -> Self
+    {
+        // Half of the circle around each end, joined by the straight sides between them
+        let angle = if from == to { math::Angle::zero() } else { (to - from).angle_from_x_axis() };
+        let half_turn = math::Angle::degrees(180.0);
+        let quarter_turn = math::Angle::degrees(90.0);
+
+        let polygon = arc_points(to, radius, angle - quarter_turn, half_turn, tolerance)
+            .into_iter()
+            .chain(arc_points(from, radius, angle + quarter_turn, half_turn, tolerance))
+            .collect();
+
+        Sweep { segment: Contour { points: vec![from, to], is_closed: false }, radius, polygon }
+    }
+
+    /// Whether the sweep covers the whole of a circle, of no radius for a point.
+    fn covers(&self, center: math::Point, radius: f32) -> bool
+    {
+        self.segment.distance_to(center) + radius <= self.radius
+    }
+
+    fn touches_dab(&self, point: &FreehandPoint) -> bool
+    {
+        self.segment.distance_to(point.position) < self.radius + point.width / 2.0
+    }
+
+    /// Whether the sweep may touch a join, which is within the widest of its ends around the segment between them.
+    fn touches_join(&self, previous: &FreehandPoint, point: &FreehandPoint) -> bool
+    {
+        let (from, to) = (self.segment.points[0], self.segment.points[1]);
+        let join = Contour { points: vec![previous.position, point.position], is_closed: false };
+        let crossing = LineSegment { from, to }.intersects(&LineSegment { from: previous.position, to: point.position });
+
+        // Segments that don't cross are nearest at one of their ends
+        let distance = match crossing
+        {
+            true => 0.0,
+            false => [join.distance_to(from), join.distance_to(to), self.segment.distance_to(previous.position), self.segment.distance_to(point.position)]
+                .iter()
+                .fold(f32::INFINITY, |nearest, &distance| nearest.min(distance)),
+        };
+
+        distance < self.radius + previous.width.max(point.width) / 2.0
+    }
+}
+
+/// The strokes of a drawing with the eraser to take them back out, see the [module level documentation](self).
+#[derive(Clone, Default)]
+pub struct FreehandCanvas
+{
+    strokes: Vec<FreehandStroke>,
+    /// The tessellation of each stroke, in the same order.
+    builders: Vec<LyonMeshBuilder>,
+}
+
+impl FreehandCanvas
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// The strokes of the drawing, from the first one drawn.
+    pub fn strokes(&self) -> &[FreehandStroke]
+    {
+        &self.strokes
+    }
+
+    /// Adds a finished stroke over the others, tessellating it.
+    pub fn add(&mut self, stroke: FreehandStroke)
+    {
+        self.builders.push(stroke.builder());
+        self.strokes.push(stroke);
+    }
+
+    /// Drops every stroke.
+    pub fn clear(&mut self)
+    {
+        self.strokes.clear();
+        self.builders.clear();
+    }
+
+    /// Sweeps an eraser of `radius` from `from` to `to` over every stroke, see [`FreehandStroke::erase`], telling how many strokes it touched.
+    ///
+    /// The pieces left of the strokes it touched take the place of the strokes they came from, so the drawing order is kept.
+    /// Only the dabs and joins the eraser went over are tessellated again, the others being copied from the tessellation of their stroke.
+    pub fn erase(&mut self, from: math::Point, to: math::Point, radius: f32) -> usize
+    {
+        let mut touched = 0;
+        let mut i = 0;
+
+        while i < self.strokes.len()
+        {
+            let stroke = &self.strokes[i];
+            let sweep = Sweep::new(from, to, radius, stroke.tolerance);
+
+            match stroke.erase_pieces(&sweep)
+            {
+                Some(pieces) =>
+                {
+                    touched += 1;
+
+                    // Where the shapes of every point start in the tessellation of the stroke
+                    let firsts: Vec<usize> = (0..stroke.points.len())
+                        .scan(0, |shapes, point| {
+                            let first = *shapes;
+                            *shapes += stroke.join(point).map_or(1, |_| 2);
+                            Some(first)
+                        })
+                        .collect();
+
+                    let builders: Vec<_> = pieces.iter()
+                        .map(|(start, piece)| {
+                            (0..piece.points.len()).fold(LyonMeshBuilder::new(), |builder, j| {
+                                let point = &piece.points[j];
+                                let dab = firsts[start + j] + stroke.join(start + j).map_or(0, |_| 1);
+
+                                // The first point of a piece lost its join, which is skipped
+                                let builder = match piece.join(j)
+                                {
+                                    Some(corners) if sweep.touches_join(&piece.points[j - 1], point) =>
+                                        builder.with_colored(piece.join_shape(corners), piece.color),
+                                    Some(_) => builder.with_base_shapes(&self.builders[i], dab - 1..dab),
+                                    None => builder,
+                                };
+
+                                match sweep.touches_dab(point)
+                                {
+                                    true => builder.with_colored(piece.dab_shape(point), piece.color),
+                                    false => builder.with_base_shapes(&self.builders[i], dab..dab + 1),
+                                }
+                            })
+                        })
+                        .collect();
+
+                    let count = pieces.len();
+                    self.strokes.splice(i..=i, pieces.into_iter().map(|(_, piece)| piece));
+                    self.builders.splice(i..=i, builders);
+                    i += count;
+                }
+                None => i += 1,
+            }
+        }
+
+        touched
+    }
+
+    /// Puts the tessellated strokes together into a single mesh builder, each stroke keeping the shapes of its dabs and joins.
+    pub fn builder(&self) -> LyonMeshBuilder
+    {
+        self.builders
+            .iter()
+            .fold(LyonMeshBuilder::new(), LyonMeshBuilder::with_base)
+    }
+
+    /// Builds the mesh of the drawing.
+    pub fn build(&self) -> Mesh
+    {
+        self.builder().build()
+    }
+}
@@ -0,0 +1,182 @@
+//! Circular gauges, the dials of speedometers and stat meters.
+//!
+//! # Overview
+//!
+//! A [`Gauge`] describes a whole dial from the range it shows and its current value: an arc for the track,
+//! a second arc over the track up to the value, tick marks along the inside of the track and a needle pointing at the value.
+//! Adding it through [`LyonMeshBuilder::with_gauge`] gives each of these parts a shape of its own,
+//! so they can be told apart through the [`ShapeRange`]s of the builder, such as to color them.
+//!
+//! The parts are also available as [`ShapeCommand`]s from [`Gauge::shapes`], to record them or build them separately.
+//!
+//! [`LyonMeshBuilder::with_gauge`]: crate::mesh_builder::LyonMeshBuilder::with_gauge
+//! [`ShapeRange`]: crate::mesh_builder::ShapeRange
+
+use std::ops::Range;
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::recording::ShapeCommand;
+use super::shapes::{
+    FillRotatedRect,
+    FillTriangle,
+    StrokeArc,
+};
+
+/// The tick marks along the inside of a gauge's track.
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct GaugeTicks
+{
+    /// How many intervals the major ticks split the range into, with a major tick at either end.
+    #[default = 10]
+    pub major_count: usize,
+    /// How many minor ticks there are between two major ones.
+    #[default = 4]
+    pub minor_count: usize,
+    #[default = 8.0]
+    pub major_length: f32,
+    #[default = 4.0]
+    pub minor_length: f32,
+    #[default = 2.0]
+    pub width: f32,
+}
+
+/// The needle of a gauge, pointing from its center at the current value.
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub enum Needle
+{
+    None,
+    /// A needle of even width.
+    #[default]
+    Line {
+        #[default = 40.0]
+        length: f32,
+        #[default = 2.0]
+        width: f32,
+    },
+    /// A needle narrowing from its base at the center to a point.
+    Tapered { length: f32, base_width: f32 },
+}
+
+/// A whole dial, see the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct Gauge
+{
+    pub center: math::Point,
+    /// The radius of the middle of the track.
+    #[default = 50.0]
+    pub radius: f32,
+    /// The values shown from the start of the track to its end.
+    #[default(0.0..100.0)]
+    pub range: Range<f32>,
+    /// The current value, which is clamped to the range.
+    pub value: f32,
+    /// Where the track starts, at the start of the range.
+    #[default(math::Angle::degrees(225.0))]
+    pub start_angle: math::Angle,
+    /// How far around the track goes, negative angles going clockwise.
+    #[default(math::Angle::degrees(-270.0))]
+    pub sweep_angle: math::Angle,
+    /// The width of the track, and of the arc showing the value over it.
+    #[default = 6.0]
+    pub arc_width: f32,
+    /// The ticks along the track, if any.
+    #[default(Some(GaugeTicks::default()))]
+    pub ticks: Option<GaugeTicks>,
+    pub needle: Needle,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Gauge
+{
+    /// How far along the range the current value is, from `0.0` at its start to `1.0` at its end.
+    pub fn fraction(&self) -> f32
+    {
+        let fraction = (self.value - self.range.start) / (self.range.end - self.range.start);
+
+        // An empty range gives NaN, which max turns into 0
+        fraction.max(0.0).min(1.0)
+    }
+
+    /// The angle a fraction of the way along the track is at.
+    pub fn angle_at(&self, fraction: f32) -> math::Angle
+    {
+        self.start_angle + self.sweep_angle * fraction
+    }
+
+    /// The parts of the dial: the track, the arc up to the value if there is one, every tick in order along the track and the needle if there is one.
+    pub fn shapes(&self) -> Vec<ShapeCommand>
+    {
+        let stroke_options = tess::StrokeOptions::tolerance(self.tolerance)
+            .with_line_width(self.arc_width);
+        let fill_options = tess::FillOptions::tolerance(self.tolerance);
+
+        let mut shapes: Vec<ShapeCommand> = vec![StrokeArc {
+            center: self.center,
+            radius: self.radius,
+            start_angle: self.start_angle,
+            sweep_angle: self.sweep_angle,
+            options: &stroke_options,
+        }.into()];
+
+        let fraction = self.fraction();
+
+        if fraction > 0.0
+        {
+            shapes.push(StrokeArc {
+                center: self.center,
+                radius: self.radius,
+                start_angle: self.start_angle,
+                sweep_angle: self.sweep_angle * fraction,
+                options: &stroke_options,
+            }.into());
+        }
+
+        if let Some(ticks) = &self.ticks
+        {
+            let count = ticks.major_count * (ticks.minor_count + 1);
+            let inner_edge = self.radius - self.arc_width / 2.0;
+
+            for i in 0..=count
+            {
+                let length = if i % (ticks.minor_count + 1) == 0 { ticks.major_length } else { ticks.minor_length };
+                let angle = self.angle_at(i as f32 / count.max(1) as f32);
+
+                shapes.push(FillRotatedRect {
+                    center: self.center + math::Vector::from_angle_and_length(angle, inner_edge - length / 2.0),
+                    size: math::size(length, ticks.width),
+                    angle,
+                    options: &fill_options,
+                }.into());
+            }
+        }
+
+        let angle = self.angle_at(fraction);
+        let direction = math::Vector::from_angle_and_length(angle, 1.0);
+
+        match self.needle
+        {
+            Needle::None => (),
+            Needle::Line { length, width } => shapes.push(FillRotatedRect {
+                center: self.center + direction * (length / 2.0),
+                size: math::size(length, width),
+                angle,
+                options: &fill_options,
+            }.into()),
+            Needle::Tapered { length, base_width } => {
+                let side = math::vector(-direction.y, direction.x) * (base_width / 2.0);
+
+                shapes.push(FillTriangle {
+                    points: [self.center - side, self.center + direction * length, self.center + side],
+                    options: &fill_options,
+                }.into());
+            },
+        }
+
+        shapes
+    }
+}
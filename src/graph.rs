@@ -0,0 +1,105 @@
+//! Graphs of nodes linked by edges, such as tech trees and network diagrams.
+//!
+//! # Overview
+//!
+//! A [`Graph`] is made from the positions of its nodes and the pairs of nodes its edges link.
+//! Its nodes are built as round markers and its edges as strokes, each into a mesh of their own,
+//! so the markers can be drawn over the ends of the edges with a material of their own.
+//!
+//! Edges are straight lines, unless they are bent to the side or bundled. Bundled edges are pulled towards the center of the graph,
+//! so that edges going the same way run together, which untangles dense graphs at the cost of their exact paths.
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::LyonMeshBuilder;
+use super::shapes::{
+    FillCircle,
+    StrokePolyline,
+    StrokeQuadraticBezier,
+};
+
+/// A graph, see the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct Graph
+{
+    pub nodes: Vec<math::Point>,
+    /// The indices of the nodes linked by each edge, edges to nodes that don't exist are left out.
+    pub edges: Vec<(usize, usize)>,
+    #[default = 5.0]
+    pub node_radius: f32,
+    #[default = 1.5]
+    pub edge_width: f32,
+    /// How far edges bend to their left, as a fraction of their length.
+    pub bend: f32,
+    /// How strongly edges are pulled towards the center of the graph, from `0.0` for not at all to `1.0` for all of them going through it.
+    pub bundling: f32,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Graph
+{
+    /// Makes a graph of straight edges.
+    pub fn new(nodes: Vec<math::Point>, edges: Vec<(usize, usize)>) -> Self
+    {
+        Graph { nodes, edges, ..Default::default() }
+    }
+
+    /// The point the curve of an edge bends towards, or nothing for straight edges.
+    pub fn edge_ctrl(&self, from: math::Point, to: math::Point) -> Option<math::Point>
+    {
+        if self.bend == 0.0 && self.bundling == 0.0
+        {
+            return None;
+        }
+
+        let center = self.nodes.iter()
+            .fold(math::point(0.0, 0.0), |sum, node| sum + node.to_vector())
+            / self.nodes.len().max(1) as f32;
+
+        let along = to - from;
+        let bent = from.lerp(to, 0.5) + math::vector(-along.y, along.x) * self.bend;
+
+        Some(bent.lerp(center, self.bundling))
+    }
+
+    /// Tessellates the markers of the nodes into a mesh builder, each as a shape of its own in the order of the nodes.
+    pub fn nodes_builder(&self) -> LyonMeshBuilder
+    {
+        let options = tess::FillOptions::tolerance(self.tolerance);
+
+        self.nodes.iter().fold(LyonMeshBuilder::new(), |builder, &center| {
+            builder.with(FillCircle { center, radius: self.node_radius, options: &options })
+        })
+    }
+
+    /// Tessellates the strokes of the edges into a mesh builder, each as a shape of its own in the order of the edges.
+    pub fn edges_builder(&self) -> LyonMeshBuilder
+    {
+        let options = tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.edge_width);
+
+        self.edges.iter()
+            .filter_map(|&(from, to)| Some((*self.nodes.get(from)?, *self.nodes.get(to)?)))
+            .fold(LyonMeshBuilder::new(), |builder, (from, to)| match self.edge_ctrl(from, to)
+            {
+                Some(ctrl) => builder.with(StrokeQuadraticBezier { from, ctrl, to, options: &options, ..Default::default() }),
+                None => builder.with(StrokePolyline { points: vec![from, to], is_closed: false, options: &options }),
+            })
+    }
+
+    /// Builds the mesh of the node markers.
+    pub fn build_nodes(&self) -> Mesh
+    {
+        self.nodes_builder().build()
+    }
+
+    /// Builds the mesh of the edge strokes.
+    pub fn build_edges(&self) -> Mesh
+    {
+        self.edges_builder().build()
+    }
+}
@@ -0,0 +1,129 @@
+//! Lasso selection, free-form loops drawn around the things to select.
+//!
+//! # Overview
+//!
+//! A [`Lasso`] collects the points of a pointer as it is dragged around, skipping points too close to the last one kept.
+//! Its polygon closes the loop back to where it started and smooths its corners, both for display and for selection,
+//! so what is selected is exactly what the drawn lasso covers. Loops crossing themselves select with the even-odd rule, like their fill.
+//!
+//! With the `bevy-0-5` feature, the optional [`LassoPlugin`] drags out the lasso of entities with a [`LassoDrag`] component from mouse input,
+//! and tells which [`LassoSelectable`] entities it covers once the drag ends.
+//!
+//! [`LassoPlugin`]: crate::plugin::LassoPlugin
+//! [`LassoDrag`]: crate::plugin::LassoDrag
+//! [`LassoSelectable`]: crate::plugin::LassoSelectable
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::LyonMeshBuilder;
+use super::shapes::{
+    polygon_contains,
+    smooth_contour,
+    FillPolyline,
+    StrokePolyline,
+};
+
+/// A lasso, see the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct Lasso
+{
+    /// The points kept so far, in the order they were drawn.
+    pub points: Vec<math::Point>,
+    /// How far a point has to be from the last point kept to be kept too.
+    #[default = 4.0]
+    pub min_distance: f32,
+    /// How many times the corners of the polygon are cut, each doubling the number of its points.
+    #[default = 2]
+    pub smoothing: usize,
+    #[default = 1.0]
+    pub border_width: f32,
+    #[default([1.0, 1.0, 1.0, 1.0])]
+    pub border_color: [f32; 4],
+    #[default([0.3, 0.6, 1.0, 0.2])]
+    pub fill_color: [f32; 4],
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Lasso
+{
+    /// Whether a point is far enough from the last point kept to be kept too.
+    pub fn accepts(&self, point: math::Point) -> bool
+    {
+        !self.points.last().is_some_and(|&last| (point - last).length() < self.min_distance)
+    }
+
+    /// Adds a point of the pointer, unless it is too close to the last point kept, telling whether it was kept.
+    pub fn push(&mut self, point: math::Point) -> bool
+    {
+        let far_enough = self.accepts(point);
+
+        if far_enough
+        {
+            self.points.push(point);
+        }
+
+        far_enough
+    }
+
+    /// Drops every point, to start a new lasso.
+    pub fn clear(&mut self)
+    {
+        self.points.clear();
+    }
+
+    /// The smoothed closed polygon of the lasso, which is empty until it has enough points to enclose anything.
+    pub fn polygon(&self) -> Vec<math::Point>
+    {
+        if self.points.len() < 3
+        {
+            return vec![];
+        }
+
+        smooth_contour(self.points.clone(), self.smoothing)
+    }
+
+    /// Whether a point is within the lasso.
+    pub fn contains(&self, point: math::Point) -> bool
+    {
+        polygon_contains(&self.polygon(), point)
+    }
+
+    /// The items whose positions are within the lasso, smoothing the lasso only once for all of them.
+    pub fn select<T>(&self, items: impl IntoIterator<Item=(T, math::Point)>) -> Vec<T>
+    {
+        let polygon = self.polygon();
+
+        items.into_iter()
+            .filter(|&(_, position)| polygon_contains(&polygon, position))
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    /// Tessellates the lasso into a mesh builder with its colors, its fill then its border, each as a shape of its own.
+    pub fn builder(&self) -> LyonMeshBuilder
+    {
+        let polygon = self.polygon();
+        let fill = tess::FillOptions::tolerance(self.tolerance).with_fill_rule(tess::FillRule::EvenOdd);
+        let border = tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.border_width);
+
+        if polygon.is_empty()
+        {
+            return LyonMeshBuilder::new();
+        }
+
+        LyonMeshBuilder::new()
+            .with_colored(FillPolyline { points: polygon.clone(), options: &fill, ..Default::default() }, self.fill_color)
+            .with_colored(StrokePolyline { points: polygon, is_closed: true, options: &border }, self.border_color)
+    }
+
+    /// Builds the mesh of the lasso.
+    pub fn build(&self) -> Mesh
+    {
+        self.builder().build()
+    }
+}
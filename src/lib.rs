@@ -1,10 +1,85 @@
+#[cfg(all(feature = "bevy-0-2", feature = "bevy-0-5"))]
+compile_error!("only one of the `bevy-0-2` and `bevy-0-5` features can be enabled at a time");
+
+#[cfg(all(feature = "headless", any(feature = "bevy-0-2", feature = "bevy-0-5")))]
+compile_error!("the `headless` feature can't be enabled alongside a version of bevy, disable the default features");
+
+#[cfg(not(any(feature = "bevy-0-2", feature = "bevy-0-5", feature = "headless")))]
+compile_error!("one of the `bevy-0-2`, `bevy-0-5` and `headless` features has to be enabled");
+
+// Lets the rest of the crate refer to bevy by the same name whichever version is enabled
+#[cfg(feature = "bevy-0-5")]
+extern crate bevy_0_5 as bevy;
+
+mod backend;
+
+/// Plain stand-ins for bevy's mesh types, which headless builds finish their meshes into.
+#[cfg(feature = "headless")]
+pub use backend::{
+    Mesh,
+    PrimitiveTopology,
+    VertexAttributeValues,
+};
+
 pub mod mesh_builder;
 
 pub mod shapes;
 
+pub mod time_sliced;
+
+pub mod recording;
+
+pub mod baked;
+
+pub mod visibility;
+
+pub mod gauge;
+
+pub mod progress_bar;
+
+pub mod minimap;
+
+pub mod graph;
+
+pub mod timeline;
+
+pub mod dungeon;
+
+pub mod territory;
+
+pub mod widgets;
+
+pub mod rating;
+
+pub mod confetti;
+
+pub mod reticle;
+
+pub mod selection;
+
+pub mod path;
+
+pub mod lasso;
+
+pub mod freehand;
+
+pub mod snapping;
+
+pub mod align;
+
+#[cfg(all(feature = "serialization", not(feature = "headless")))]
+pub mod loader;
+
+#[cfg(feature = "bevy-0-5")]
+pub mod plugin;
+
 #[doc(inline)]
 pub use shapes::{
-    LyonShapeBuilder
+    LyonShapeBuilder,
+    LyonShapeOutline,
+    Contour,
+    CurveFlattening,
+    Corners,
 };
 
 #[doc(inline)]
@@ -13,9 +88,171 @@ pub use mesh_builder::{
     BevyVertex,
     BevyVertexBuffers,
     BevyBuffersBuilder,
+    ShapeRange,
+    BuilderSnapshot,
+    LightmapUvs,
+    VertexLayout,
+    NormalGeneration,
+    Falloff,
+    Feather,
+    ShadowLight,
+    SoftShadow,
+    Envelope,
+    PolarWrap,
+    VectorFieldStyle,
+    PolylineStyle,
 
     LyonMeshBuilder,
 };
 
-#[doc(no_inline)]
-pub use lyon::math;
\ No newline at end of file
+#[doc(inline)]
+pub use time_sliced::{
+    TessellationProgress,
+    TimeSlicedBuilder,
+};
+
+#[doc(inline)]
+pub use recording::{
+    ShapeCommand,
+    RecordingBuilder,
+    OperationLog,
+    ShapeOperation,
+};
+
+#[doc(inline)]
+pub use baked::{
+    BakedMesh,
+    MassProperties,
+};
+
+#[cfg(feature = "serialization")]
+#[doc(inline)]
+pub use baked::BakedMeshError;
+
+#[doc(inline)]
+pub use visibility::VisibilityPolygon;
+
+#[doc(inline)]
+pub use gauge::{
+    Gauge,
+    GaugeTicks,
+    Needle,
+};
+
+#[doc(inline)]
+pub use progress_bar::ProgressBar;
+
+#[doc(inline)]
+pub use minimap::Minimap;
+
+#[doc(inline)]
+pub use graph::Graph;
+
+#[doc(inline)]
+pub use timeline::{
+    Timeline,
+    TimelineClip,
+};
+
+#[doc(inline)]
+pub use dungeon::{
+    Corridor,
+    Dungeon,
+};
+
+#[doc(inline)]
+pub use territory::Territories;
+
+#[doc(inline)]
+pub use widgets::{
+    Widget,
+    Checkbox,
+    RadioButton,
+    Toggle,
+};
+
+#[doc(inline)]
+pub use rating::StarRating;
+
+#[doc(inline)]
+pub use confetti::{
+    Confetti,
+    ConfettiKind,
+};
+
+#[doc(inline)]
+pub use reticle::Reticle;
+
+#[doc(inline)]
+pub use selection::SelectionRect;
+
+#[doc(inline)]
+pub use path::{
+    BevyPathBuilder,
+    IntoPathPoint,
+};
+
+#[doc(inline)]
+pub use lasso::Lasso;
+
+#[doc(inline)]
+pub use freehand::{
+    FreehandCanvas,
+    FreehandPoint,
+    FreehandStroke,
+};
+
+#[doc(inline)]
+pub use snapping::Snapping;
+
+#[doc(inline)]
+pub use align::{
+    align,
+    distribute,
+    fit_to_rect,
+    group_bounds,
+    Alignment,
+    Axis,
+};
+
+#[cfg(all(feature = "serialization", not(feature = "headless")))]
+#[doc(inline)]
+pub use loader::LyonMeshLoader;
+
+#[cfg(feature = "bevy-0-5")]
+#[doc(inline)]
+pub use plugin::{
+    LyonPlugin,
+    LyonDiagnosticsPlugin,
+    LyonMaterialPlugin,
+    LyonSettings,
+    LyonShape,
+    LyonShapeGroup,
+    ResolutionSensitive,
+    ResolutionPolicy,
+    DashedStroke,
+    ViewportQuad,
+    ViewportAnchor,
+    MinimapViewport,
+    SelectionDrag,
+    FreehandDrawing,
+    LassoDrag,
+    LassoFinished,
+    LassoPlugin,
+    LassoSelectable,
+    Anchor,
+    TessellationCache,
+    TessellationTask,
+    TessellationProgressEvent,
+    TessellationFailed,
+};
+
+/// The math types used throughout the crate, which are the ones from [`lyon::math`].
+pub mod math
+{
+    #[doc(no_inline)]
+    pub use lyon::math::*;
+
+    /// Alias for `euclid::default::Rect<f32>`, which lyon no longer provides since it moved to [`Box2D`].
+    pub type Rect = lyon::geom::euclid::default::Rect<f32>;
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+//! Loading of `.lyonmesh` files as bevy meshes.
+//!
+//! The [`LyonMeshLoader`] has to be registered with the app, after which `.lyonmesh` files
+//! written by the `lyon-bake` tool (or [`BakedMesh::to_bytes`]) load like any other mesh asset:
+//!
+//! ```rust,ignore
+//! // bevy 0.2
+//! app.add_asset_loader::<Mesh, LyonMeshLoader>();
+//! // bevy 0.5
+//! app.init_asset_loader::<LyonMeshLoader>();
+//! ```
+
+use super::baked::BakedMesh;
+use super::mesh_builder::LyonMeshBuilder;
+
+/// Asset loader turning `.lyonmesh` files into meshes, see the [module level documentation](self).
+#[derive(Debug, Default)]
+pub struct LyonMeshLoader;
+
+impl LyonMeshLoader
+{
+    /// The file extensions handled by the loader.
+    pub const EXTENSIONS: &'static [&'static str] = &["lyonmesh"];
+}
+
+#[cfg(feature = "bevy-0-2")]
+impl bevy::asset::AssetLoader<bevy::render::mesh::Mesh> for LyonMeshLoader
+{
+    fn from_bytes(&self, _asset_path: &std::path::Path, bytes: Vec<u8>) -> Result<bevy::render::mesh::Mesh, anyhow::Error>
+    {
+        Ok(LyonMeshBuilder::from(BakedMesh::from_bytes(&bytes)?).build())
+    }
+
+    fn extensions(&self) -> &[&str]
+    {
+        Self::EXTENSIONS
+    }
+}
+
+#[cfg(feature = "bevy-0-5")]
+impl bevy::asset::AssetLoader for LyonMeshLoader
+{
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut bevy::asset::LoadContext,
+    ) -> bevy::utils::BoxedFuture<'a, Result<(), anyhow::Error>>
+    {
+        Box::pin(async move {
+            let mesh = LyonMeshBuilder::from(BakedMesh::from_bytes(bytes)?).build();
+            load_context.set_default_asset(bevy::asset::LoadedAsset::new(mesh));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str]
+    {
+        Self::EXTENSIONS
+    }
+}
@@ -7,23 +7,50 @@
 //! 
 
 
-use bevy::render::{
-    mesh::{
-        VertexAttribute,
-        Mesh
-    },
-    pipeline::PrimitiveTopology,
+use std::{
+    fmt,
+    ops::Range,
+    rc::Rc,
 };
 
-use lyon::{
-    math::{
-        self,
-        Point
-    },
-    tessellation as tess,
+#[cfg(feature = "serialization")]
+use serde::{
+    Deserialize,
+    Serialize,
 };
 
-use super::shapes::LyonShapeBuilder;
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+
+use super::baked::BakedMesh;
+
+use super::gauge::Gauge;
+
+use super::timeline::Timeline;
+
+use super::widgets::Widget;
+
+use super::backend::{
+    self,
+    Mesh,
+    MeshAttributes,
+    PrimitiveTopology,
+    VertexAttributeValues,
+};
+
+use super::shapes::{
+    is_hole,
+    signed_area,
+    Arrow,
+    FillSubtracted,
+    LyonShapeBuilder,
+    LyonShapeOutline,
+    StrokePolyline,
+    StrokeQuadraticBezier,
+};
 
 /// Type alias for the type of a mesh index in [`bevy`].
 pub type BevyIndex = u32;
@@ -32,20 +59,327 @@ pub type BevyVertexBuffers = tess::VertexBuffers<BevyVertex, BevyIndex>;
 /// Type alias for a [`BuffersBuilder`](tess::BuffersBuilder) that contains the information to properly convert [`lyon`] points to [`BevyVertex`]'s and [`BevyIndex`]'s.
 pub type BevyBuffersBuilder<'a> = tess::BuffersBuilder<'a, BevyVertex, BevyIndex, BevyVertexConstructor>;
 
+/// Type of the shape generators stored by [`LyonMeshBuilder::with_lod`], called with the tolerance to tessellate at.
+type LodShape = Rc<dyn Fn(f32, &mut BevyBuffersBuilder)>;
+
+/// The ranges of the vertex and index buffers that were produced by a single shape added to a [`LyonMeshBuilder`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct ShapeRange
+{
+    pub vertices: Range<BevyIndex>,
+    pub indices: Range<BevyIndex>,
+}
+
+/// The sizes of a [`LyonMeshBuilder`]'s buffers at some point, which it can be rolled back to, see [`LyonMeshBuilder::snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuilderSnapshot
+{
+    vertices: usize,
+    indices: usize,
+    shapes: usize,
+    lod_shapes: usize,
+}
+
+/// Ways of generating the second uv channel of a mesh, see [`LyonMeshBuilder::with_lightmap_uvs`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LightmapUvs
+{
+    /// Every shape gets its own island in the uv square, its geometry is left untouched.
+    PerShape { padding: f32 },
+    /// Every triangle gets its own island in the uv square, which requires unsharing all of the mesh's vertices.
+    PerTriangle { padding: f32 },
+}
+
+/// Which of the optional vertex attributes are emitted by a [`LyonMeshBuilder`], see [`LyonMeshBuilder::with_vertex_layout`].
+///
+/// Bevy's vertex attributes are all made of 32-bit floats, so leaving out attributes a pipeline doesn't use is how vertices are made smaller.
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct VertexLayout
+{
+    /// Always `+Z` for flat geometry, so unlit pipelines can usually go without it.
+    #[default = true]
+    pub normals: bool,
+    #[default = true]
+    pub uvs: bool,
+    /// Emitted as [`LyonMeshBuilder::ATTRIBUTE_COLOR`], off by default since most shapes are colored by their material.
+    pub colors: bool,
+    /// Emitted as [`LyonMeshBuilder::ATTRIBUTE_SHAPE_INDEX`], for shaders animating each shape of a mesh on its own.
+    pub shape_indices: bool,
+}
+
+/// Ways of computing the normals of a mesh, see [`LyonMeshBuilder::with_normals`].
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub enum NormalGeneration
+{
+    /// Every vertex gets the `+Z` normal, which is exact for all geometry lying in the XY plane.
+    #[default]
+    Constant,
+    /// Every face is lit evenly, using the normal of its plane.
+    Flat,
+    /// The normals of neighbouring faces are averaged, unless the angle between them is larger than `max_angle`.
+    Smooth { max_angle: math::Angle },
+}
+
+/// How the opacity of a feathered edge fades out across its width, see [`LyonMeshBuilder::with_feathered_fill`].
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub enum Falloff
+{
+    /// The opacity drops evenly from the edge of the shape to the end of the feather.
+    #[default]
+    Linear,
+    /// The opacity eases in and out of the fade, which looks softer for wide feathers such as shadows.
+    Smoothstep,
+}
+
+impl Falloff
+{
+    /// How much of the opacity is gone at `t`, from `0.0` at the edge of the shape to `1.0` at the end of the feather.
+    pub fn apply(&self, t: f32) -> f32
+    {
+        match self
+        {
+            Falloff::Linear => t,
+            Falloff::Smoothstep => t * t * (3.0 - 2.0 * t),
+        }
+    }
+
+    /// Internal utility function that gives the number of rings a feather is made of, a linear fade only needs one.
+    fn rings(&self) -> usize
+    {
+        match self
+        {
+            Falloff::Linear => 1,
+            Falloff::Smoothstep => 4,
+        }
+    }
+}
+
+/// A soft edge fading out around a shape, see [`LyonMeshBuilder::with_feathered_fill`].
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct Feather
+{
+    /// How far the fade extends outwards from the edge of the shape.
+    #[default = 1.0]
+    pub width: f32,
+    pub falloff: Falloff,
+}
+
+/// A bilinear warp stretching a rect onto any four corners, such as for a perspective-like tilt, see [`LyonMeshBuilder::with_warped`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Envelope
+{
+    /// The area of the shape that is warped, points outside of it are extrapolated.
+    pub bounds: math::Rect,
+    /// Where the corners of the bounds end up, counter-clockwise from its minimum: `[min, (max.x, min.y), max, (min.x, max.y)]`.
+    pub corners: [math::Point; 4],
+}
+
+impl Envelope
+{
+    /// Makes an envelope that leaves the bounds as they are, ready to have its corners moved.
+    pub fn new(bounds: math::Rect) -> Self
+    {
+        let (min, max) = (bounds.min(), bounds.max());
+
+        Envelope {
+            bounds,
+            corners: [min, math::point(max.x, min.y), max, math::point(min.x, max.y)],
+        }
+    }
+
+    /// Moves a point from the bounds to where it ends up in the envelope.
+    ///
+    /// Bounds without an area can't be stretched onto the corners, so they leave every point as it is.
+    pub fn warp(&self, point: math::Point) -> math::Point
+    {
+        if self.bounds.width() <= 0.0 || self.bounds.height() <= 0.0
+        {
+            return point;
+        }
+
+        let u = (point.x - self.bounds.min_x()) / self.bounds.width();
+        let v = (point.y - self.bounds.min_y()) / self.bounds.height();
+
+        let bottom = self.corners[0].lerp(self.corners[1], u);
+        let top = self.corners[3].lerp(self.corners[2], u);
+        bottom.lerp(top, v)
+    }
+}
+
+/// A warp wrapping a straight strip around a circle, its X becoming the angle and its Y the radius, see [`LyonMeshBuilder::with_polar_wrapped`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolarWrap
+{
+    pub center: math::Point,
+    /// The radius of the circle that a Y of zero ends up on, with greater Y further out.
+    pub radius: f32,
+    /// Where an X of zero ends up around the circle.
+    pub start_angle: math::Angle,
+    /// How far around the circle the strip goes, negative angles going clockwise.
+    pub sweep_angle: math::Angle,
+    /// The length along X that covers the whole sweep.
+    pub length: f32,
+}
+
+impl PolarWrap
+{
+    /// Makes a wrap going clockwise from the top all the way around the circle, like the face of a clock.
+    pub fn new(center: math::Point, radius: f32, length: f32) -> Self
+    {
+        PolarWrap {
+            center,
+            radius,
+            start_angle: math::Angle::frac_pi_2(),
+            sweep_angle: -math::Angle::two_pi(),
+            length,
+        }
+    }
+
+    /// Moves a point from the strip to where it ends up around the circle.
+    pub fn warp(&self, point: math::Point) -> math::Point
+    {
+        let angle = self.start_angle + self.sweep_angle * (point.x / self.length);
+        let (sin, cos) = angle.sin_cos();
+
+        self.center + math::vector(cos, sin) * (self.radius + point.y)
+    }
+}
+
+/// The light a shadow is cast from, see [`LyonMeshBuilder::with_soft_shadow`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShadowLight
+{
+    /// A round light at a position, whose penumbra gets wider the closer the light is to the shape.
+    Point { position: math::Point, radius: f32 },
+    /// A far away light shining along `direction`, such as the sun, with `spread` being the angular radius of its penumbra.
+    Directional { direction: math::Vector, spread: math::Angle },
+}
+
+/// A shadow cast by a shape, with a solid umbra and a penumbra fading out at its sides, see [`LyonMeshBuilder::with_soft_shadow`].
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct SoftShadow
+{
+    #[default(ShadowLight::Directional { direction: math::vector(1.0, -1.0), spread: math::Angle::degrees(5.0) })]
+    pub light: ShadowLight,
+    /// How far the shadow is cast away from the shape.
+    #[default = 100.0]
+    pub length: f32,
+    /// The opacity of the shadow right behind the shape.
+    #[default = 0.5]
+    pub opacity: f32,
+    /// How much of the opacity is lost by the end of the shadow, from `0.0` for a shadow that doesn't fade to `1.0` for one that fades out entirely.
+    #[default = 1.0]
+    pub fade: f32,
+}
+
+/// How the arrows of a sampled vector field look, see [`LyonMeshBuilder::with_vector_field`].
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct VectorFieldStyle
+{
+    /// The distance between the samples along both axes, which has to be positive.
+    #[default = 20.0]
+    pub spacing: f32,
+    /// How long an arrow is for each unit of the magnitude of its vector.
+    #[default = 10.0]
+    pub scale: f32,
+    /// The longest an arrow gets, to keep it from running into its neighbors.
+    #[default = 18.0]
+    pub max_length: f32,
+    #[default = 1.5]
+    pub shaft_width: f32,
+    #[default = 5.0]
+    pub head_length: f32,
+    #[default = 5.0]
+    pub head_width: f32,
+    /// The magnitude that the end of the gradient is reached at.
+    #[default = 1.0]
+    pub max_magnitude: f32,
+    /// The color stops of the gradient the arrows are colored by, at offsets from `0.0` for no magnitude to `1.0` for `max_magnitude`.
+    #[default(vec![(0.0, [0.0, 0.0, 1.0, 1.0]), (1.0, [1.0, 0.0, 0.0, 1.0])])]
+    pub stops: Vec<(f32, [f32; 4])>,
+}
+
+/// A preset look of a line on a map, which may take several strokes over each other, see [`LyonMeshBuilder::with_styled_polyline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PolylineStyle
+{
+    /// A light road over a darker casing that shows `casing_width` past either of its sides.
+    Road { width: f32, casing_width: f32, color: [f32; 4], casing_color: [f32; 4] },
+    /// A river whose width grows with its stream order, from `base_width` for the smallest streams by `width_per_order` for each order above the first.
+    River { order: u32, base_width: f32, width_per_order: f32, color: [f32; 4] },
+}
+
+impl PolylineStyle
+{
+    /// A road of the given width, light gray over a dark gray casing.
+    pub fn road(width: f32) -> Self
+    {
+        PolylineStyle::Road {
+            width,
+            casing_width: width * 0.2,
+            color: [0.95, 0.95, 0.9, 1.0],
+            casing_color: [0.3, 0.3, 0.3, 1.0],
+        }
+    }
+
+    /// A blue river of the given stream order.
+    pub fn river(order: u32) -> Self
+    {
+        PolylineStyle::River {
+            order,
+            base_width: 1.0,
+            width_per_order: 1.5,
+            color: [0.25, 0.5, 0.85, 1.0],
+        }
+    }
+
+    /// The strokes the line is made of in the order they are drawn in, from the bottom up, as their width and color.
+    pub fn passes(&self) -> Vec<(f32, [f32; 4])>
+    {
+        match *self
+        {
+            PolylineStyle::Road { width, casing_width, color, casing_color } =>
+                vec![(width + casing_width * 2.0, casing_color), (width, color)],
+            PolylineStyle::River { order, base_width, width_per_order, color } =>
+                vec![(base_width + width_per_order * order.saturating_sub(1) as f32, color)],
+        }
+    }
+}
+
 /// Builder that provides customizable functionality to create [`lyon`](lyon) tessellated meshes and build them so [`bevy`](bevy) can consume them.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct LyonMeshBuilder
 {
-    geometry: BevyVertexBuffers
+    geometry: BevyVertexBuffers,
+    shapes: Vec<ShapeRange>,
+    lod_shapes: Vec<LodShape>,
+    lightmap_uvs: Option<LightmapUvs>,
+    vertex_layout: VertexLayout,
+    normal_generation: NormalGeneration,
 }
 
 impl LyonMeshBuilder
 {
+    /// Name of the vertex attribute holding the uvs generated by [`LyonMeshBuilder::with_lightmap_uvs`].
+    pub const ATTRIBUTE_UV_1: &'static str = "Vertex_Uv1";
+
+    /// Name of the vertex attribute holding the vertex colors, such as the ones of [`LyonMeshBuilder::with_stroke_gradient`] or [`LyonMeshBuilder::build_points`].
+    pub const ATTRIBUTE_COLOR: &'static str = "Vertex_Color";
+
+    /// Name of the vertex attribute holding the index of the shape each vertex belongs to, in the order the shapes were added.
+    pub const ATTRIBUTE_SHAPE_INDEX: &'static str = "Vertex_ShapeIndex";
+
     /// Create a new mesh builder.
     pub fn new() -> Self
     {
         LyonMeshBuilder {
-            geometry: BevyVertexBuffers::new()
+            geometry: BevyVertexBuffers::new(),
+            shapes: vec![],
+            lod_shapes: vec![],
+            lightmap_uvs: None,
+            vertex_layout: VertexLayout::default(),
+            normal_generation: NormalGeneration::default(),
         }
     }
 
@@ -60,110 +394,1502 @@ impl LyonMeshBuilder
     /// Finishes a mesh using a specific [`PrimitiveTopology`].
     ///
     /// Prefer using [`LyonMeshBuilder::build`] as its default topology works in the vast majority of cases.
-    pub fn build_with_topology(self, topology: PrimitiveTopology) -> Mesh
+    pub fn build_with_topology(mut self, topology: PrimitiveTopology) -> Mesh
+    {
+        self.bake_lod_shapes(tess::FillOptions::DEFAULT_TOLERANCE);
+        self.into_mesh(topology)
+    }
+
+    /// Finishes a mesh without an index buffer, every triangle getting its own three vertices.
+    ///
+    /// Meant for pipelines and effects that require non-indexed geometry, such as per-face attributes.
+    /// Uses [`TriangleList`](PrimitiveTopology::TriangleList) as the primitive topology, since the vertices are no longer shared.
+    pub fn build_non_indexed(mut self) -> Mesh
+    {
+        self.bake_lod_shapes(tess::FillOptions::DEFAULT_TOLERANCE);
+        self.unshare_vertices();
+
+        let (attributes, _) = self.into_mesh_data(PrimitiveTopology::TriangleList);
+        backend::mesh(PrimitiveTopology::TriangleList, attributes, None)
+    }
+
+    /// Finishes a mesh made of a point for every generated vertex, useful to visualize tessellation density when choosing tolerances.
+    ///
+    /// When `color_by_shape` is set, the points get a [`LyonMeshBuilder::ATTRIBUTE_COLOR`] attribute
+    /// giving every shape its own color, so it's easy to tell which shape generated which vertices.
+    pub fn build_points(mut self, color_by_shape: bool) -> Mesh
+    {
+        self.bake_lod_shapes(tess::FillOptions::DEFAULT_TOLERANCE);
+
+        if color_by_shape
+        {
+            self.color_by_shape();
+            self.vertex_layout.colors = true;
+        }
+
+        self.geometry.indices = (0..self.geometry.vertices.len() as BevyIndex).collect();
+
+        let (attributes, indices) = self.into_mesh_data(PrimitiveTopology::PointList);
+        backend::mesh(PrimitiveTopology::PointList, attributes, Some(indices))
+    }
+
+    /// Finishes the mesh once per tolerance given, producing a chain of meshes with decreasing (or increasing) level of detail.
+    ///
+    /// Only the shapes added with [`LyonMeshBuilder::with_lod`] are tessellated again for each tolerance,
+    /// the geometry of every other shape is tessellated once and shared by all the meshes.
+    pub fn build_lod_chain(self, tolerances: &[f32]) -> Vec<Mesh>
+    {
+        tolerances.iter()
+            .map(|&tolerance| {
+                let mut lod = self.clone();
+                lod.bake_lod_shapes(tolerance);
+                lod.into_mesh(PrimitiveTopology::TriangleStrip)
+            })
+            .collect()
+    }
+
+    /// Finishes the mesh with each shape's uvs packed into its own region of a shared atlas.
+    ///
+    /// Normally every shape's uvs are its positions, so shapes built into the same mesh overlap in texture space.
+    /// Instead, each shape's uvs are normalized to its bounding box and fit into a cell of a grid covering the `[0, 1]` uv square,
+    /// keeping their aspect ratio and leaving `padding` uv units free around each cell.
+    ///
+    /// Returns the uv rect of every shape, in the order the shapes were added (shapes added with [`LyonMeshBuilder::with_lod`] come last).
+    pub fn build_with_uv_atlas(mut self, padding: f32) -> (Mesh, Vec<math::Rect>)
+    {
+        self.bake_lod_shapes(tess::FillOptions::DEFAULT_TOLERANCE);
+
+        let mut uv_rects = Vec::with_capacity(self.shapes.len());
+
+        for (i, range) in self.shapes.iter().enumerate()
+        {
+            let vertices = &mut self.geometry.vertices[range.vertices.start as usize..range.vertices.end as usize];
+            let cell = atlas_cell(i, self.shapes.len(), padding);
+            let (uv_rect, to_uv) = fit_island(vertex_bounds(vertices.iter()), cell);
+
+            for vertex in vertices
+            {
+                vertex.uv = to_uv(vertex);
+            }
+
+            uv_rects.push(uv_rect);
+        }
+
+        (self.into_mesh(PrimitiveTopology::TriangleStrip), uv_rects)
+    }
+
+    /// Finishes the tessellated geometry into its raw vertex and index buffers, without making a mesh out of them.
+    ///
+    /// Useful when the geometry is consumed outside of bevy's renderer, such as by servers or asset pipelines using the `headless` feature.
+    /// Normals are generated as for triangles, while the vertex layout and lightmap uvs only apply to meshes and are ignored.
+    pub fn build_buffers(mut self) -> BevyVertexBuffers
+    {
+        self.bake_lod_shapes(tess::FillOptions::DEFAULT_TOLERANCE);
+        self.generate_normals();
+        self.geometry
+    }
+
+    /// Finishes the tessellated geometry into a [`BakedMesh`], which can be stored and turned back into a builder without tessellating again.
+    ///
+    /// Normals are generated as for triangles, while the vertex layout and lightmap uvs are left to whoever builds the baked mesh.
+    pub fn bake(mut self) -> BakedMesh
     {
-        Mesh {
-            primitive_topology: topology,
-            attributes: self.verts_to_attributes(),
-            indices: Some(self.geometry.indices),
+        self.bake_lod_shapes(tess::FillOptions::DEFAULT_TOLERANCE);
+        self.generate_normals();
+
+        BakedMesh {
+            bounds: vertex_bounds(self.geometry.vertices.iter()),
+            vertices: self.geometry.vertices,
+            indices: self.geometry.indices,
+            shapes: self.shapes,
         }
     }
 
     /// Adds a shape specified by argument's [`LyonShapeBuilder`] implementation to the mesh being constructed.
     pub fn with(mut self, shape: impl LyonShapeBuilder) -> Self
     {
-        shape.build(&mut self.buffers_builder());
+        self.add_shape(shape);
         self
     }
 
-    /// A convenience function that makes a new [`LyonMeshBuilder`] and builds it with only the single shape provided.
+    /// Adds a shape like [`LyonMeshBuilder::with`], but fails if lyon couldn't tessellate it.
     ///
-    /// This is equivalent to calling:
-    /// ```rust
-    /// # use bevy::render::mesh::Mesh;
-    /// # use bevy_lyon::{
-    /// #    LyonShapeBuilder,
-    /// #    LyonMeshBuilder
-    /// # };
-    /// # fn with_only_example(shape: impl LyonShapeBuilder) -> Mesh
-    /// # {
-    /// LyonMeshBuilder::new()
-    ///     .with(shape)
-    ///     .build()
-    /// # }
-    /// ```
-    pub fn with_only(shape: impl LyonShapeBuilder) -> Mesh
+    /// A shape that failed to tessellate doesn't add any geometry to the mesh.
+    pub fn try_with(mut self, shape: impl LyonShapeBuilder) -> Result<Self, tess::TessellationError>
     {
-        LyonMeshBuilder::new()
-            .with(shape)
-            .build()
+        let mut result = Ok(());
+        self.record_shape(|this| result = shape.try_build(&mut this.buffers_builder()));
+
+        result.map(|_| self)
     }
 
-    /// Internal utility function to simplify creation of an output buffer builder.
-    fn buffers_builder(&mut self) -> tess::BuffersBuilder<BevyVertex, BevyIndex, BevyVertexConstructor>
+    /// Adds a shape with an affine transform applied to its vertices, such as one from an SVG transform stack or a skewed design.
+    ///
+    /// The whole matrix is baked in, so any mix of translation, rotation, scale and skew is reproduced exactly.
+    /// Transforms that mirror the shape also flip its triangles back, so they keep their winding. The uvs are left untransformed.
+    pub fn with_transformed(self, shape: impl LyonShapeBuilder, transform: &math::Transform) -> Self
     {
-        tess::BuffersBuilder::new(&mut self.geometry, BevyVertexConstructor)
+        let mut this = self.with_warped(shape, |point| transform.transform_point(point));
+
+        if transform.determinant() < 0.0
+        {
+            this.flip_last_shape();
+        }
+
+        this
     }
 
-    /// Internal utility function that transforms an iterator of `BevyVertex`'s into the proper array of vertex attributes.
-    fn verts_to_attributes(&self) -> Vec<VertexAttribute>
+    /// Adds a shape with every one of its tessellated vertices moved through a warp, such as an [`Envelope`], a flag wave or a fisheye.
+    ///
+    /// Only the vertices are moved, so the edges between them stay straight: shapes that should bend smoothly
+    /// need enough vertices along them, such as from a smaller tolerance. The uvs are left unwarped.
+    pub fn with_warped(mut self, shape: impl LyonShapeBuilder, warp: impl Fn(math::Point) -> math::Point) -> Self
     {
-        let mut positions = vec![];
-        let mut normals = vec![];
-        let mut uvs = vec![];
-    
-        for vertex in &self.geometry.vertices
+        self.record_shape(|this| {
+            let vertices_start = this.geometry.vertices.len();
+
+            shape.build(&mut this.buffers_builder());
+
+            for vertex in &mut this.geometry.vertices[vertices_start..]
+            {
+                let point = warp(math::point(vertex.pos[0], vertex.pos[1]));
+                vertex.pos[0] = point.x;
+                vertex.pos[1] = point.y;
+            }
+        });
+        self
+    }
+
+    /// Adds a shape sheared along X by `x` times its Y coordinates, and along Y by `y` times its X coordinates, such as for italic UI elements.
+    ///
+    /// The shear is around the origin, so a shape made around the origin stays in place. See [`LyonMeshBuilder::with_transformed`].
+    ///
+    /// The vertices are sheared after the shape is tessellated, so strokes are sheared along with their width, getting thinner across slanted edges.
+    /// Strokes that have to keep their width need their outline sheared before they are stroked, such as the points of a [`StrokePolyline`].
+    pub fn with_sheared(self, shape: impl LyonShapeBuilder, x: f32, y: f32) -> Self
+    {
+        self.with_transformed(shape, &math::Transform::new(1.0, y, x, 1.0, 0.0, 0.0))
+    }
+
+    /// Adds a shape authored in a straight strip wrapped around a circle, such as a ruler's tick marks becoming a gauge's.
+    ///
+    /// Like any warp only the vertices are moved, so long edges along the strip need enough vertices to bend around the circle.
+    /// Counter-clockwise wraps mirror the strip, so they also flip its triangles back to keep their winding. See [`PolarWrap`].
+    pub fn with_polar_wrapped(self, shape: impl LyonShapeBuilder, wrap: &PolarWrap) -> Self
+    {
+        let mut this = self.with_warped(shape, |point| wrap.warp(point));
+
+        if wrap.sweep_angle.radians > 0.0
         {
-            positions.push(vertex.pos);
-            normals.push(vertex.norm);
-            uvs.push(vertex.uv);
+            this.flip_last_shape();
         }
 
-        vec![
-            VertexAttribute::position(positions),
-            VertexAttribute::normal(normals),
-            VertexAttribute::uv(uvs),
-        ]
+        this
     }
-}
 
-/// Utility type for containing the trait implementations that transforms a lyon point into a `BevyVertex`.
-pub struct BevyVertexConstructor;
+    /// Adds the dial of a [`Gauge`], with each of its parts as a shape of its own in the order of [`Gauge::shapes`].
+    pub fn with_gauge(mut self, gauge: &Gauge) -> Self
+    {
+        for shape in gauge.shapes()
+        {
+            self.add_shape(shape);
+        }
+        self
+    }
 
-// TODO: Figure out if uv mapping should be specific for this
-impl tess::BasicVertexConstructor<BevyVertex> for BevyVertexConstructor
-{
-    fn new_vertex(&mut self, point: Point) -> BevyVertex
+    /// Adds the parts of a [`Timeline`], each as a shape of its own in the order of [`Timeline::shapes`].
+    pub fn with_timeline(mut self, timeline: &Timeline) -> Self
     {
-        point.into()
+        for shape in timeline.shapes()
+        {
+            self.add_shape(shape);
+        }
+        self
     }
-}
 
-// TODO: Figure out if uv mapping should be specific for this
-impl tess::FillVertexConstructor<BevyVertex> for BevyVertexConstructor
-{
-    fn new_vertex(&mut self, point: Point, _: tess::FillAttributes) -> BevyVertex
+    /// Adds the parts of a [`Widget`], such as a checkbox, each as a shape of its own in the order of [`Widget::shapes`].
+    pub fn with_widget(mut self, widget: &impl Widget) -> Self
     {
-        point.into()
+        for shape in widget.shapes()
+        {
+            self.add_shape(shape);
+        }
+        self
     }
-}
 
-// TODO: Figure out if uv mapping should be specific for this
-impl tess::StrokeVertexConstructor<BevyVertex> for BevyVertexConstructor
-{
-    fn new_vertex(&mut self, point: Point, _: tess::StrokeAttributes) -> BevyVertex
+    /// Adds a shape whose uvs map its bounding box onto a region of the uv square, such as the sprite of an atlas.
+    ///
+    /// The bounding box is stretched to cover the whole region, so many shapes merged in one mesh can share a single textured material.
+    pub fn with_uv_rect(mut self, shape: impl LyonShapeBuilder, uv_rect: math::Rect) -> Self
+    {
+        self.add_shape(shape);
+
+        let range = self.shapes.last().map_or(0..0, |range| range.vertices.start as usize..range.vertices.end as usize);
+        let vertices = &mut self.geometry.vertices[range];
+        let bounds = vertex_bounds(vertices.iter());
+
+        for vertex in vertices
+        {
+            let x = if bounds.size.width > 0.0 { (vertex.pos[0] - bounds.origin.x) / bounds.size.width } else { 0.0 };
+            let y = if bounds.size.height > 0.0 { (vertex.pos[1] - bounds.origin.y) / bounds.size.height } else { 0.0 };
+
+            vertex.uv = [uv_rect.origin.x + x * uv_rect.size.width, uv_rect.origin.y + y * uv_rect.size.height];
+        }
+
+        self
+    }
+
+    /// Makes the finished mesh have a second uv channel, named [`LyonMeshBuilder::ATTRIBUTE_UV_1`], in which no two islands overlap.
+    ///
+    /// This is what is needed to bake lightmaps or decals onto the geometry, the first uv channel is left as is.
+    pub fn with_lightmap_uvs(mut self, lightmap_uvs: LightmapUvs) -> Self
+    {
+        self.lightmap_uvs = Some(lightmap_uvs);
+        self
+    }
+
+    /// Chooses which of the optional vertex attributes the finished mesh has, shrinking its memory footprint.
+    pub fn with_vertex_layout(mut self, vertex_layout: VertexLayout) -> Self
+    {
+        self.vertex_layout = vertex_layout;
+        self
+    }
+
+    /// Adds the flattened outline of a shape as lines instead of tessellated triangles, such as the centerline of a stroke shape.
+    ///
+    /// The lines are emitted as pairs of indices, so the mesh should be finished with
+    /// [`LineList`](PrimitiveTopology::LineList) topology and not mixed with shapes added with [`LyonMeshBuilder::with`].
+    /// Finishing it with [`LineStrip`](PrimitiveTopology::LineStrip) draws the same lines, but joins the contours together.
+    pub fn with_centerline(mut self, shape: impl LyonShapeOutline, tolerance: f32) -> Self
+    {
+        self.record_shape(|this| {
+            for contour in shape.outline(tolerance)
+            {
+                let first = this.geometry.vertices.len() as BevyIndex;
+                this.geometry.vertices.extend(contour.points.into_iter().map(BevyVertex::from));
+                let last = this.geometry.vertices.len() as BevyIndex;
+
+                for i in first + 1..last
+                {
+                    this.geometry.indices.extend_from_slice(&[i - 1, i]);
+                }
+
+                if contour.is_closed && last > first + 2
+                {
+                    this.geometry.indices.extend_from_slice(&[last - 1, first]);
+                }
+            }
+        });
+        self
+    }
+
+    /// Adds a shape with every one of its vertices in a single color, such as to batch differently colored shapes into one mesh.
+    ///
+    /// The colors are baked into the vertices, so this turns on [`VertexLayout::colors`].
+    pub fn with_colored(mut self, shape: impl LyonShapeBuilder, color: [f32; 4]) -> Self
+    {
+        self.vertex_layout.colors = true;
+
+        self.record_shape(|this| {
+            let vertices_start = this.geometry.vertices.len();
+
+            shape.build(&mut this.buffers_builder());
+
+            for vertex in &mut this.geometry.vertices[vertices_start..]
+            {
+                vertex.color = color;
+            }
+        });
+        self
+    }
+
+    /// Adds a polyline drawn in a preset style, such as a road over its casing, with every stroke of the style as a shape of its own from the bottom up.
+    ///
+    /// Strokes have round joins and caps, so that the strokes under them show evenly all around. Strokes added later are drawn over earlier ones
+    /// within a mesh, so the casings of roads crossing each other are covered by both roads.
+    /// The colors are baked into the vertices, so this turns on [`VertexLayout::colors`].
+    pub fn with_styled_polyline(self, points: &[math::Point], style: &PolylineStyle, tolerance: f32) -> Self
     {
-        point.into()
+        style.passes().into_iter().fold(self, |builder, (width, color)| {
+            let options = tess::StrokeOptions::tolerance(tolerance)
+                .with_line_width(width)
+                .with_line_join(tess::LineJoin::Round)
+                .with_line_cap(tess::LineCap::Round);
+
+            builder.with_colored(StrokePolyline { points: points.iter().copied(), is_closed: false, options: &options }, color)
+        })
+    }
+
+    /// Fills any number of rects in one go, each with its own color, such as highlighted tiles.
+    ///
+    /// All of the rects are recorded as a single shape. The colors are baked into the vertices, so this turns on [`VertexLayout::colors`].
+    pub fn with_colored_rects(mut self, rects: impl IntoIterator<Item=(math::Rect, [f32; 4])>, options: &tess::FillOptions) -> Self
+    {
+        self.vertex_layout.colors = true;
+
+        self.record_shape(|this| {
+            let mut tessellator = tess::FillTessellator::new();
+
+            for (rect, color) in rects
+            {
+                let _ = tessellator.tessellate_rectangle(
+                    &rect.to_box2d(),
+                    options,
+                    &mut tess::BuffersBuilder::new(&mut this.geometry, ColorVertexConstructor { color })
+                );
+            }
+        });
+        self
+    }
+
+    /// Adds an [`Arrow`] for every sample of a vector field over a grid within `bounds`, such as to debug steering or wind.
+    ///
+    /// Each arrow is centered on its sample, scaled and colored by the magnitude of its vector, and samples without a direction are left out.
+    /// All of the arrows are recorded as a single shape. The colors are baked into the vertices, so this turns on [`VertexLayout::colors`].
+    /// The spacing has to be positive, the shape is left empty otherwise.
+    pub fn with_vector_field(mut self, bounds: math::Rect, field: impl Fn(math::Point) -> math::Vector, style: &VectorFieldStyle, options: &tess::FillOptions) -> Self
+    {
+        self.vertex_layout.colors = true;
+
+        // A spacing of zero would sample the field endlessly
+        let (columns, rows) = if style.spacing.is_nan() || style.spacing <= 0.0
+        {
+            (0, 0)
+        }
+        else
+        {
+            ((bounds.width() / style.spacing).floor().max(0.0) as usize, (bounds.height() / style.spacing).floor().max(0.0) as usize)
+        };
+
+        self.record_shape(|this| {
+            let mut tessellator = tess::FillTessellator::new();
+
+            for (column, row) in (0..rows).flat_map(|row| (0..columns).map(move |column| (column, row)))
+            {
+                let sample = bounds.min() + math::vector(column as f32 + 0.5, row as f32 + 0.5) * style.spacing;
+                let vector = field(sample);
+                let magnitude = vector.length();
+
+                let direction = match vector.try_normalize()
+                {
+                    Some(direction) => direction,
+                    None => continue,
+                };
+
+                let half_length = (magnitude * style.scale).min(style.max_length) / 2.0;
+                let arrow = Arrow {
+                    from: sample - direction * half_length,
+                    to: sample + direction * half_length,
+                    shaft_width: style.shaft_width,
+                    head_length: style.head_length,
+                    head_width: style.head_width,
+                    options,
+                    ..Default::default()
+                };
+                let color = sample_gradient(&style.stops, magnitude / style.max_magnitude);
+
+                let _ = tessellator.tessellate_polygon(
+                    lyon::path::Polygon { points: &arrow.corners(), closed: true },
+                    options,
+                    &mut tess::BuffersBuilder::new(&mut this.geometry, ColorVertexConstructor { color })
+                );
+            }
+        });
+        self
+    }
+
+    /// Adds the stroke of a shape's outline, colored by a gradient running along the length of each of its contours.
+    ///
+    /// The gradient's stops are pairs of an offset, from `0.0` at the start of a contour to `1.0` at its end, and the color at that offset.
+    /// The colors are baked into the vertices, so this turns on [`VertexLayout::colors`].
+    pub fn with_stroke_gradient(mut self, shape: impl LyonShapeOutline, options: &tess::StrokeOptions, stops: &[(f32, [f32; 4])]) -> Self
+    {
+        self.vertex_layout.colors = true;
+
+        self.record_shape(|this| {
+            for contour in shape.outline(options.tolerance)
+            {
+                let constructor = GradientVertexConstructor { stops, length: contour_length(&contour.points, contour.is_closed) };
+
+                let _ = tess::StrokeTessellator::new().tessellate_polygon(
+                    lyon::path::Polygon { points: &contour.points, closed: contour.is_closed },
+                    options,
+                    &mut tess::BuffersBuilder::new(&mut this.geometry, constructor)
+                );
+            }
+        });
+        self
+    }
+
+    /// Adds the fill of a shape's outline, surrounded by a feathered edge whose opacity fades out with the given falloff.
+    ///
+    /// A thin feather softens the edges of a shape, while a wide one makes soft shadows and glows.
+    /// Every closed contour is feathered away from the filled area, holes within an odd number of the other contours being feathered into the hole.
+    /// The opacity is baked into the vertex colors, so this turns on [`VertexLayout::colors`].
+    pub fn with_feathered_fill(mut self, shape: impl LyonShapeOutline, options: &tess::FillOptions, feather: Feather) -> Self
+    {
+        self.vertex_layout.colors = true;
+
+        self.record_shape(|this| {
+            let contours = shape.outline(options.tolerance);
+
+            let mut path = lyon::path::Path::builder();
+            for contour in &contours
+            {
+                path.add_polygon(lyon::path::Polygon { points: &contour.points, closed: true });
+            }
+
+            let _ = tess::FillTessellator::new().tessellate_path(&path.build(), options, &mut this.buffers_builder());
+
+            let closed: Vec<_> = contours.into_iter().filter(|contour| contour.is_closed).collect();
+
+            for (i, contour) in closed.iter().enumerate()
+            {
+                feather_contour(&mut this.geometry, &contour.points, is_hole(&closed, i), &feather);
+            }
+        });
+        self
+    }
+
+    /// Adds the fill of a shape's outline with the outline of another shape cut out of it, such as a panel with a round hole in it.
+    ///
+    /// The cutter is clipped to the base when the base is a single convex contour, so it may stick out of it.
+    /// Otherwise the cutter should lie within the base, as the parts of it sticking out would be filled instead.
+    /// The fill rule of `options` is ignored, the hole is made with [`EvenOdd`](tess::FillRule::EvenOdd).
+    pub fn with_subtracted(mut self, base: impl LyonShapeOutline, cutter: impl LyonShapeOutline, options: &tess::FillOptions) -> Self
+    {
+        self.add_shape(FillSubtracted { base, cutter, options });
+        self
+    }
+
+    /// Adds the stroke of a curve with its vertices colored by the subdivision depth they were flattened at, to see where a curve gets dense or faceted.
+    ///
+    /// Points with the same depth share a color, so runs of one color are where the curve was flat enough to stop subdividing early.
+    /// The colors are baked into the vertices, so this turns on [`VertexLayout::colors`].
+    pub fn with_flattening_debug(mut self, curve: StrokeQuadraticBezier) -> Self
+    {
+        self.vertex_layout.colors = true;
+
+        self.record_shape(|this| {
+            let (points, depths): (Vec<_>, Vec<_>) = curve.flattening.flatten(&curve.segment(), curve.options.tolerance)
+                .into_iter()
+                .unzip();
+
+            let _ = tess::StrokeTessellator::new().tessellate_polygon(
+                lyon::path::Polygon { points: &points, closed: false },
+                curve.options,
+                &mut tess::BuffersBuilder::new(&mut this.geometry, DepthVertexConstructor { depths: &depths })
+            );
+        });
+        self
+    }
+
+    /// Adds the symmetric difference of two shapes' outlines, filled with a single color, to see where a shape strays from a reference outline.
+    ///
+    /// The area covered by exactly one of the shapes is filled, while the area they share is left empty.
+    /// The fill rule of `options` is ignored, the difference is made with [`EvenOdd`](tess::FillRule::EvenOdd).
+    /// The color is baked into the vertices, so this turns on [`VertexLayout::colors`].
+    pub fn with_difference_debug(mut self, shape: impl LyonShapeOutline, reference: impl LyonShapeOutline, options: &tess::FillOptions, color: [f32; 4]) -> Self
+    {
+        self.vertex_layout.colors = true;
+
+        self.record_shape(|this| {
+            let mut path = lyon::path::Path::builder();
+
+            for contour in shape.outline(options.tolerance).iter().chain(&reference.outline(options.tolerance))
+            {
+                if contour.points.len() > 2
+                {
+                    path.add_polygon(lyon::path::Polygon { points: &contour.points, closed: true });
+                }
+            }
+
+            let _ = tess::FillTessellator::new().tessellate_path(
+                &path.build(),
+                &options.with_fill_rule(tess::FillRule::EvenOdd),
+                &mut tess::BuffersBuilder::new(&mut this.geometry, ColorVertexConstructor { color })
+            );
+        });
+        self
+    }
+
+    /// Adds the shadow a shape's outline casts away from a light, made of black vertices whose opacity gives the shadow its softness.
+    ///
+    /// The shape itself isn't added, so its fill is usually added right after for the shadow to be drawn behind it.
+    /// Only closed contours cast shadows. Shadows of concave shapes can overlap themselves, which draws those parts darker.
+    /// The opacity is baked into the vertex colors, so this turns on [`VertexLayout::colors`].
+    pub fn with_soft_shadow(mut self, shape: impl LyonShapeOutline, tolerance: f32, shadow: &SoftShadow) -> Self
+    {
+        self.vertex_layout.colors = true;
+
+        self.record_shape(|this| {
+            for contour in shape.outline(tolerance).iter().filter(|contour| contour.is_closed)
+            {
+                shadow_contour(&mut this.geometry, &contour.points, shadow);
+            }
+        });
+        self
+    }
+
+    /// Adds the area within `margin` of the stroke of a shape's outline, for picking strokes too thin to click on comfortably.
+    ///
+    /// This is meant for a builder of its own, whose mesh is used for picking and never drawn, so the visible stroke keeps its width.
+    /// The area has round joins and caps, so it is the same distance out all around the stroke,
+    /// which [`Contour::distance_to`](crate::shapes::Contour::distance_to) tests points against without building a mesh.
+    pub fn with_hit_area(self, shape: impl LyonShapeOutline, options: &tess::StrokeOptions, margin: f32) -> Self
+    {
+        let options = options
+            .with_line_width(options.line_width + margin * 2.0)
+            .with_line_join(tess::LineJoin::Round)
+            .with_line_cap(tess::LineCap::Round);
+
+        shape.outline(options.tolerance)
+            .into_iter()
+            .fold(self, |builder, contour| {
+                builder.with(StrokePolyline { points: contour.points, is_closed: contour.is_closed, options: &options })
+            })
+    }
+
+    /// Adds the stroke of a shape's outline, broken into dashes.
+    ///
+    /// The dash pattern alternates between the lengths of dashes and gaps, starting with a dash, and repeats along each contour.
+    /// Lengths that aren't positive count as empty, and a pattern with no length at all draws the whole stroke.
+    /// `offset` is how far into the pattern each contour starts, so increasing it over time makes the dashes march backwards along the outline.
+    pub fn with_dashed_stroke(mut self, shape: impl LyonShapeOutline, options: &tess::StrokeOptions, dashes: &[f32], offset: f32) -> Self
+    {
+        self.record_shape(|this| {
+            for contour in shape.outline(options.tolerance)
+            {
+                for dash in dash_contour(&contour.points, contour.is_closed, dashes, offset)
+                {
+                    let _ = tess::StrokeTessellator::new().tessellate_polygon(
+                        lyon::path::Polygon { points: &dash, closed: false },
+                        options,
+                        &mut this.buffers_builder()
+                    );
+                }
+            }
+        });
+        self
+    }
+
+    /// Chooses how the normals of the finished mesh are computed, for geometry that doesn't lie flat in the XY plane.
+    ///
+    /// Faces are expected to be wound counter-clockwise when seen from the side their normal points to.
+    pub fn with_normals(mut self, normal_generation: NormalGeneration) -> Self
+    {
+        self.normal_generation = normal_generation;
+        self
+    }
+
+    /// Adds a shape whose tessellation depends on the tolerance chosen when the mesh is finished.
+    ///
+    /// The closure is called with the tolerance to use each time the mesh is built, which lets
+    /// [`LyonMeshBuilder::build_lod_chain`] produce the shape at several levels of detail.
+    /// When finished with [`LyonMeshBuilder::build`], the default lyon tolerance is used.
+    ///
+    /// ```rust
+    /// # use lyon::tessellation::FillOptions;
+    /// # use bevy_lyon::{
+    /// #    math,
+    /// #    shapes,
+    /// #    LyonShapeBuilder,
+    /// #    LyonMeshBuilder
+    /// # };
+    /// let lods = LyonMeshBuilder::new()
+    ///     .with_lod(|tolerance, builder| shapes::FillCircle {
+    ///         radius: 100.0,
+    ///         options: &FillOptions::tolerance(tolerance),
+    ///         ..Default::default()
+    ///     }.build(builder))
+    ///     .build_lod_chain(&[0.05, 0.5, 2.0]);
+    /// ```
+    pub fn with_lod<F>(mut self, shape: F) -> Self
+    where
+        F: Fn(f32, &mut BevyBuffersBuilder) + 'static
+    {
+        self.lod_shapes.push(Rc::new(shape));
+        self
+    }
+
+    /// A convenience function that makes a new [`LyonMeshBuilder`] and builds it with only the single shape provided.
+    ///
+    /// This is equivalent to calling:
+    /// ```rust
+    /// # use bevy::render::mesh::Mesh;
+    /// # use bevy_lyon::{
+    /// #    LyonShapeBuilder,
+    /// #    LyonMeshBuilder
+    /// # };
+    /// # fn with_only_example(shape: impl LyonShapeBuilder) -> Mesh
+    /// # {
+    /// LyonMeshBuilder::new()
+    ///     .with(shape)
+    ///     .build()
+    /// # }
+    /// ```
+    pub fn with_only(shape: impl LyonShapeBuilder) -> Mesh
+    {
+        LyonMeshBuilder::new()
+            .with(shape)
+            .build()
+    }
+
+    /// Copies the shapes already added to another builder into this one, without tessellating them again.
+    ///
+    /// This is the fast path for meshes sharing a common base with small additions of their own, such as a panel with a different badge on each:
+    /// the base is tessellated once, then copied into the builder of every variant.
+    /// The base's shapes added with [`LyonMeshBuilder::with_lod`] are shared rather than copied.
+    /// None of the base's settings are copied, except that its vertex colors are kept if it had them.
+    pub fn with_base(mut self, base: &LyonMeshBuilder) -> Self
+    {
+        let vertex_offset = self.geometry.vertices.len() as BevyIndex;
+        let index_offset = self.geometry.indices.len() as BevyIndex;
+
+        self.geometry.vertices.extend_from_slice(&base.geometry.vertices);
+        self.geometry.indices.extend(base.geometry.indices.iter().map(|index| index + vertex_offset));
+        self.shapes.extend(base.shapes.iter().map(|range| ShapeRange {
+            vertices: range.vertices.start + vertex_offset..range.vertices.end + vertex_offset,
+            indices: range.indices.start + index_offset..range.indices.end + index_offset,
+        }));
+        self.lod_shapes.extend(base.lod_shapes.iter().cloned());
+        self.vertex_layout.colors |= base.vertex_layout.colors;
+        self.vertex_layout.shape_indices |= base.vertex_layout.shape_indices;
+        self
+    }
+
+    /// Copies some of the shapes already added to another builder into this one, without tessellating them again, see [`LyonMeshBuilder::with_base`].
+    ///
+    /// This is how a builder is put back together after a few of its shapes changed, copying the others and tessellating only the changed ones.
+    /// Shapes past the end of the base are left out, and its shapes added with [`LyonMeshBuilder::with_lod`] are never copied.
+    pub fn with_base_shapes(mut self, base: &LyonMeshBuilder, shapes: Range<usize>) -> Self
+    {
+        let end = shapes.end.min(base.shapes.len());
+        let shapes = &base.shapes[shapes.start.min(end)..end];
+
+        if let (Some(first), Some(last)) = (shapes.first(), shapes.last())
+        {
+            let (vertices, indices) = (first.vertices.start..last.vertices.end, first.indices.start..last.indices.end);
+            let vertex_offset = self.geometry.vertices.len() as BevyIndex;
+            let index_offset = self.geometry.indices.len() as BevyIndex;
+
+            // The copied vertices move from where they were in the base to the end of this builder's vertices
+            self.geometry.vertices.extend_from_slice(&base.geometry.vertices[vertices.start as usize..vertices.end as usize]);
+            self.geometry.indices.extend(
+                base.geometry.indices[indices.start as usize..indices.end as usize].iter().map(|index| index - vertices.start + vertex_offset)
+            );
+            self.shapes.extend(shapes.iter().map(|range| ShapeRange {
+                vertices: range.vertices.start - vertices.start + vertex_offset..range.vertices.end - vertices.start + vertex_offset,
+                indices: range.indices.start - indices.start + index_offset..range.indices.end - indices.start + index_offset,
+            }));
+        }
+
+        self.vertex_layout.colors |= base.vertex_layout.colors;
+        self.vertex_layout.shape_indices |= base.vertex_layout.shape_indices;
+        self
+    }
+
+    /// Modifies the vertices of one of the shapes in place, in the order the shapes were added, telling whether there was such a shape.
+    ///
+    /// This is the partial update of geometry that only moves, such as the arms of a [`Reticle`](crate::reticle::Reticle) as its spread changes,
+    /// which leaves the other shapes as they are and doesn't tessellate anything again.
+    pub fn transform_shape_vertices(&mut self, shape: usize, transform: impl FnMut(&mut BevyVertex)) -> bool
+    {
+        match self.shapes.get(shape)
+        {
+            Some(range) =>
+            {
+                self.geometry.vertices[range.vertices.start as usize..range.vertices.end as usize].iter_mut().for_each(transform);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remembers how many shapes were added so far, so shapes added afterwards can be dropped with [`LyonMeshBuilder::restore`].
+    ///
+    /// This is how speculative geometry, such as the preview of a drag, is added and taken back out without cloning the builder.
+    pub fn snapshot(&self) -> BuilderSnapshot
+    {
+        BuilderSnapshot {
+            vertices: self.geometry.vertices.len(),
+            indices: self.geometry.indices.len(),
+            shapes: self.shapes.len(),
+            lod_shapes: self.lod_shapes.len(),
+        }
+    }
+
+    /// Drops every shape added since the snapshot was taken, which must have been taken from this builder.
+    ///
+    /// Settings changed since then, such as the vertex layout, are kept.
+    pub fn restore(&mut self, snapshot: BuilderSnapshot)
+    {
+        self.geometry.vertices.truncate(snapshot.vertices);
+        self.geometry.indices.truncate(snapshot.indices);
+        self.shapes.truncate(snapshot.shapes);
+        self.lod_shapes.truncate(snapshot.lod_shapes);
+    }
+
+    /// Internal utility function that tessellates the shapes added with [`LyonMeshBuilder::with_lod`] at the given tolerance.
+    fn bake_lod_shapes(&mut self, tolerance: f32)
+    {
+        let lod_shapes = std::mem::take(&mut self.lod_shapes);
+
+        for shape in &lod_shapes
+        {
+            self.add_shape(|builder: &mut BevyBuffersBuilder| shape(tolerance, builder));
+        }
+    }
+
+    /// Internal utility function that tessellates a shape and records the ranges of the buffers it produced.
+    pub(crate) fn add_shape(&mut self, shape: impl LyonShapeBuilder)
+    {
+        self.record_shape(|this| shape.build(&mut this.buffers_builder()));
+    }
+
+    /// Internal utility function that gives the number of vertices tessellated so far.
+    pub(crate) fn vertex_count(&self) -> usize
+    {
+        self.geometry.vertices.len()
+    }
+
+    /// Internal utility function that records the ranges of the buffers produced by a function adding a single shape.
+    fn record_shape(&mut self, add: impl FnOnce(&mut Self))
+    {
+        let vertices_start = self.geometry.vertices.len() as BevyIndex;
+        let indices_start = self.geometry.indices.len() as BevyIndex;
+
+        add(self);
+
+        self.shapes.push(ShapeRange {
+            vertices: vertices_start..self.geometry.vertices.len() as BevyIndex,
+            indices: indices_start..self.geometry.indices.len() as BevyIndex,
+        });
+    }
+
+    /// Internal utility function that reverses the winding of the triangles of the last shape added.
+    fn flip_last_shape(&mut self)
+    {
+        let range = self.shapes.last().map_or(0..0, |range| range.indices.start as usize..range.indices.end as usize);
+
+        for triangle in self.geometry.indices[range].chunks_exact_mut(3)
+        {
+            triangle.swap(1, 2);
+        }
+    }
+
+    /// Internal utility function that converts the tessellated geometry into a mesh.
+    fn into_mesh(self, topology: PrimitiveTopology) -> Mesh
+    {
+        let (attributes, indices) = self.into_mesh_data(topology);
+        backend::mesh(topology, attributes, Some(indices))
+    }
+
+    /// Internal utility function that finishes the tessellated geometry into the vertex attributes and indices of a mesh.
+    fn into_mesh_data(mut self, topology: PrimitiveTopology) -> (MeshAttributes, Vec<BevyIndex>)
+    {
+        // Normals can only be derived from faces, lines and points keep the constant normal
+        if let PrimitiveTopology::TriangleList | PrimitiveTopology::TriangleStrip = topology
+        {
+            self.generate_normals();
+        }
+
+        let lightmap_uvs = self.lightmap_uvs.map(|lightmap_uvs| self.generate_lightmap_uvs(lightmap_uvs));
+        let mut attributes = self.verts_to_attributes();
+
+        if let Some(lightmap_uvs) = lightmap_uvs
+        {
+            attributes.push((Self::ATTRIBUTE_UV_1, VertexAttributeValues::Float2(lightmap_uvs)));
+        }
+
+        (attributes, self.geometry.indices)
+    }
+
+    /// Internal utility function that replaces the normals of the vertices according to the normal generation policy.
+    fn generate_normals(&mut self)
+    {
+        let max_angle = match self.normal_generation
+        {
+            NormalGeneration::Constant => return,
+            NormalGeneration::Flat => 0.0,
+            NormalGeneration::Smooth { max_angle } => max_angle.radians,
+        };
+
+        // Every corner of every face gets its own vertex, so faces meeting at a sharp angle can have different normals
+        let original_indices = self.geometry.indices.clone();
+        self.unshare_vertices();
+
+        let face_normals: Vec<_> = self.geometry.vertices.chunks(3)
+            .map(|face| face_normal(&face[0].pos, &face[1].pos, &face[2].pos))
+            .collect();
+
+        let mut faces_around = vec![vec![]; original_indices.iter().max().map_or(0, |&max| max as usize + 1)];
+        for (corner, &index) in original_indices.iter().enumerate()
+        {
+            faces_around[index as usize].push(corner / 3);
+        }
+
+        let min_cos = max_angle.cos();
+
+        for (corner, &index) in original_indices.iter().enumerate()
+        {
+            let normal = face_normals[corner / 3];
+
+            // Face normals aren't normalized, so larger faces weigh more in the average
+            let smoothed = faces_around[index as usize].iter()
+                .map(|&face| face_normals[face])
+                .filter(|other| cos_between(&normal, other) >= min_cos - f32::EPSILON)
+                .fold([0.0; 3], |sum, other| [sum[0] + other[0], sum[1] + other[1], sum[2] + other[2]]);
+
+            self.geometry.vertices[corner].norm = normalized(&smoothed).unwrap_or([0.0, 0.0, 1.0]);
+        }
+    }
+
+    /// Internal utility function that gives every vertex the color of the shape it belongs to.
+    fn color_by_shape(&mut self)
+    {
+        for (i, range) in self.shapes.iter().enumerate()
+        {
+            let color = debug_color(i);
+
+            for vertex in &mut self.geometry.vertices[range.vertices.start as usize..range.vertices.end as usize]
+            {
+                vertex.color = color;
+            }
+        }
+    }
+
+    /// Internal utility function that duplicates the vertices so that every index refers to its own vertex.
+    fn unshare_vertices(&mut self)
+    {
+        self.geometry.vertices = self.geometry.indices.iter()
+            .map(|&index| self.geometry.vertices[index as usize].clone())
+            .collect();
+        self.geometry.indices = (0..self.geometry.vertices.len() as BevyIndex).collect();
+
+        // Every index now has the vertex at the same position, so each shape's vertices are where its indices were
+        for range in &mut self.shapes
+        {
+            range.vertices = range.indices.clone();
+        }
+    }
+
+    /// Internal utility function that computes the uvs of the second uv channel.
+    fn generate_lightmap_uvs(&mut self, lightmap_uvs: LightmapUvs) -> Vec<[f32; 2]>
+    {
+        let mut uvs = vec![[0.0, 0.0]; self.geometry.vertices.len()];
+
+        match lightmap_uvs
+        {
+            LightmapUvs::PerShape { padding } => {
+                for (i, range) in self.shapes.iter().enumerate()
+                {
+                    let range = range.vertices.start as usize..range.vertices.end as usize;
+                    let bounds = vertex_bounds(self.geometry.vertices[range.clone()].iter());
+                    let (_, to_uv) = fit_island(bounds, atlas_cell(i, self.shapes.len(), padding));
+
+                    for vertex in range
+                    {
+                        uvs[vertex] = to_uv(&self.geometry.vertices[vertex]);
+                    }
+                }
+            },
+            LightmapUvs::PerTriangle { padding } => {
+                // Vertices shared between triangles can't be in two islands at once, so every triangle gets its own vertices
+                self.unshare_vertices();
+                let triangle_count = self.geometry.vertices.len() / 3;
+
+                uvs = self.geometry.vertices.chunks(3)
+                    .enumerate()
+                    .flat_map(|(i, triangle)| {
+                        let (_, to_uv) = fit_island(vertex_bounds(triangle.iter()), atlas_cell(i, triangle_count, padding));
+                        triangle.iter().map(to_uv).collect::<Vec<_>>()
+                    })
+                    .collect();
+            },
+        }
+
+        uvs
+    }
+
+    /// Internal utility function to simplify creation of an output buffer builder.
+    fn buffers_builder(&mut self) -> tess::BuffersBuilder<BevyVertex, BevyIndex, BevyVertexConstructor>
+    {
+        tess::BuffersBuilder::new(&mut self.geometry, BevyVertexConstructor)
+    }
+
+    /// Internal utility function that transforms an iterator of `BevyVertex`'s into the proper array of vertex attributes.
+    fn verts_to_attributes(&self) -> MeshAttributes
+    {
+        let mut positions = vec![];
+        let mut normals = vec![];
+        let mut uvs = vec![];
+        let mut colors = vec![];
+    
+        for vertex in &self.geometry.vertices
+        {
+            positions.push(vertex.pos);
+            normals.push(vertex.norm);
+            uvs.push(vertex.uv);
+            colors.push(vertex.color);
+        }
+
+        let mut attributes = vec![(backend::ATTRIBUTE_POSITION, VertexAttributeValues::Float3(positions))];
+
+        if self.vertex_layout.normals
+        {
+            attributes.push((backend::ATTRIBUTE_NORMAL, VertexAttributeValues::Float3(normals)));
+        }
+
+        if self.vertex_layout.uvs
+        {
+            attributes.push((backend::ATTRIBUTE_UV, VertexAttributeValues::Float2(uvs)));
+        }
+
+        if self.vertex_layout.colors
+        {
+            attributes.push((Self::ATTRIBUTE_COLOR, VertexAttributeValues::Float4(colors)));
+        }
+
+        if self.vertex_layout.shape_indices
+        {
+            let mut shape_indices = vec![0.0; self.geometry.vertices.len()];
+
+            for (i, range) in self.shapes.iter().enumerate()
+            {
+                for vertex in &mut shape_indices[range.vertices.start as usize..range.vertices.end as usize]
+                {
+                    *vertex = i as f32;
+                }
+            }
+
+            attributes.push((Self::ATTRIBUTE_SHAPE_INDEX, VertexAttributeValues::Float(shape_indices)));
+        }
+
+        attributes
+    }
+}
+
+/// Internal utility function that computes the normal of a counter-clockwise face, with a length of twice the face's area.
+fn face_normal(a: &[f32; 3], b: &[f32; 3], c: &[f32; 3]) -> [f32; 3]
+{
+    let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+/// Internal utility function that scales a vector to a length of one, if it has any length at all.
+fn normalized(v: &[f32; 3]) -> Option<[f32; 3]>
+{
+    let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+
+    if length > 0.0 { Some([v[0] / length, v[1] / length, v[2] / length]) } else { None }
+}
+
+/// Internal utility function that computes the cosine of the angle between two vectors.
+fn cos_between(a: &[f32; 3], b: &[f32; 3]) -> f32
+{
+    match (normalized(a), normalized(b))
+    {
+        (Some(a), Some(b)) => a[0] * b[0] + a[1] * b[1] + a[2] * b[2],
+        _ => 1.0,
+    }
+}
+
+/// Internal utility function that computes the length of a contour, including its closing segment if it has one.
+fn contour_length(points: &[math::Point], is_closed: bool) -> f32
+{
+    let open_length: f32 = points.windows(2)
+        .map(|segment| (segment[1] - segment[0]).length())
+        .sum();
+
+    match (is_closed, points.first(), points.last())
+    {
+        (true, Some(first), Some(last)) => open_length + (*first - *last).length(),
+        _ => open_length,
+    }
+}
+
+/// Internal utility function that adds the rings of a feathered edge around a closed contour, into the hole when it is one.
+fn feather_contour(geometry: &mut BevyVertexBuffers, points: &[math::Point], is_hole: bool, feather: &Feather)
+{
+    // Consecutive duplicates have no direction to feather along
+    let mut points = points.to_vec();
+    points.dedup();
+    if points.len() > 1 && points[0] == points[points.len() - 1]
+    {
+        points.pop();
+    }
+
+    if points.len() < 3 || feather.width.is_nan() || feather.width <= 0.0
+    {
+        return;
+    }
+
+    // The contour keeps its winding, the outside of the filled area being on the right of counter-clockwise outer contours and clockwise holes
+    let side = if (signed_area(&points) < 0.0) == is_hole { 1.0 } else { -1.0 };
+
+    let count = points.len();
+    let rings = feather.falloff.rings();
+    let first = geometry.vertices.len() as BevyIndex;
+
+    let outward = |from: math::Point, to: math::Point| {
+        let direction = (to - from).normalize();
+        math::Vector::new(direction.y, -direction.x) * side
+    };
+
+    for (i, &point) in points.iter().enumerate()
+    {
+        let before = outward(points[(i + count - 1) % count], point);
+        let after = outward(point, points[(i + 1) % count]);
+
+        // Mitered so the feather keeps its width along both edges, clamped so sharp corners don't spike out
+        let miter = (before + after).try_normalize().unwrap_or(after);
+        let offset = miter * (feather.width / miter.dot(after).max(0.25));
+
+        for ring in 0..=rings
+        {
+            let t = ring as f32 / rings as f32;
+
+            geometry.vertices.push(BevyVertex {
+                color: [1.0, 1.0, 1.0, 1.0 - feather.falloff.apply(t)],
+                ..(point + offset * t).into()
+            });
+        }
+    }
+
+    let vertex = |point: usize, ring: usize| first + (point * (rings + 1) + ring) as BevyIndex;
+
+    for i in 0..count
+    {
+        let j = (i + 1) % count;
+
+        // Walking with the outside on the left winds the triangles the other way, which is turned back
+        let (i, j) = if side > 0.0 { (i, j) } else { (j, i) };
+
+        for ring in 0..rings
+        {
+            geometry.indices.extend_from_slice(&[
+                vertex(i, ring), vertex(i, ring + 1), vertex(j, ring + 1),
+                vertex(i, ring), vertex(j, ring + 1), vertex(j, ring),
+            ]);
+        }
+    }
+}
+
+/// Internal utility function that checks whether a closed contour turns the same way at all of its corners.
+pub(crate) fn is_convex(points: &[math::Point]) -> bool
+{
+    let count = points.len();
+
+    let turns: Vec<f32> = (0..count)
+        .map(|i| {
+            let (a, b, c) = (points[i], points[(i + 1) % count], points[(i + 2) % count]);
+            (b - a).cross(c - b)
+        })
+        .filter(|turn| turn.abs() > f32::EPSILON)
+        .collect();
+
+    count > 2 && (turns.iter().all(|&turn| turn > 0.0) || turns.iter().all(|&turn| turn < 0.0))
+}
+
+/// Internal utility function that adds the umbra and penumbra a closed contour casts.
+fn shadow_contour(geometry: &mut BevyVertexBuffers, points: &[math::Point], shadow: &SoftShadow)
+{
+    // The number of triangles each side of the penumbra is made of
+    const PENUMBRA_STEPS: usize = 4;
+
+    if points.len() < 3
+    {
+        return;
+    }
+
+    // Walking the contour counter-clockwise puts its outside on the right
+    let area: f32 = points.iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum();
+
+    let points: Vec<_> = if area < 0.0 { points.iter().rev().copied().collect() } else { points.to_vec() };
+    let count = points.len();
+    let center = points.iter().fold(math::Point::origin(), |sum, point| sum + point.to_vector() / count as f32);
+
+    // Which way the light travels at a point, and the angular radius of the light seen from there
+    let light_at = |point: math::Point| match shadow.light
+    {
+        ShadowLight::Point { position, radius } => {
+            let offset = point - position;
+            let spread = (radius / offset.length().max(f32::EPSILON)).min(1.0).asin();
+            (offset.try_normalize().unwrap_or_default(), spread)
+        },
+        ShadowLight::Directional { direction, spread } => (direction.try_normalize().unwrap_or_default(), spread.radians),
+    };
+
+    let far_opacity = shadow.opacity * (1.0 - shadow.fade);
+
+    let push = |geometry: &mut BevyVertexBuffers, point: math::Point, opacity: f32| {
+        geometry.vertices.push(BevyVertex { color: [0.0, 0.0, 0.0, opacity], ..point.into() });
+        geometry.vertices.len() as BevyIndex - 1
+    };
+
+    // Edges facing away from the light are the ones casting the umbra
+    let casting: Vec<bool> = (0..count)
+        .map(|i| {
+            let (from, to) = (points[i], points[(i + 1) % count]);
+            let edge = to - from;
+            math::vector(edge.y, -edge.x).dot(light_at(from.lerp(to, 0.5)).0) > 0.0
+        })
+        .collect();
+
+    for i in (0..count).filter(|&i| casting[i])
+    {
+        let (from, to) = (points[i], points[(i + 1) % count]);
+
+        let near = [push(geometry, from, shadow.opacity), push(geometry, to, shadow.opacity)];
+        let far = [
+            push(geometry, from + light_at(from).0 * shadow.length, far_opacity),
+            push(geometry, to + light_at(to).0 * shadow.length, far_opacity),
+        ];
+
+        geometry.indices.extend_from_slice(&[near[0], far[0], far[1], near[0], far[1], near[1]]);
+    }
+
+    // The penumbra fans out from the points where the contour stops casting the umbra, away from the shadow
+    for i in (0..count).filter(|&i| casting[i] != casting[(i + count - 1) % count])
+    {
+        let point = points[i];
+        let (direction, spread) = light_at(point);
+        let outwards = -direction.cross(center - point).signum();
+
+        let tip = push(geometry, point, shadow.opacity);
+        let fan: Vec<_> = (0..=PENUMBRA_STEPS)
+            .map(|step| {
+                let t = step as f32 / PENUMBRA_STEPS as f32;
+                let rotated = math::Rotation::radians(outwards * spread * t).transform_vector(direction);
+                push(geometry, point + rotated * shadow.length, far_opacity * (1.0 - t))
+            })
+            .collect();
+
+        for pair in fan.windows(2)
+        {
+            geometry.indices.extend_from_slice(&[tip, pair[0], pair[1]]);
+        }
+    }
+}
+
+/// Internal utility function that clips a closed contour to the inside of a convex one, with the Sutherland-Hodgman algorithm.
+pub(crate) fn clip_to_convex(points: &[math::Point], convex: &[math::Point]) -> Vec<math::Point>
+{
+    let area: f32 = convex.iter()
+        .zip(convex.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum();
+
+    let mut clipped = points.to_vec();
+
+    for (&from, &to) in convex.iter().zip(convex.iter().cycle().skip(1))
+    {
+        // Positive on the inner side of the edge, whichever way the convex contour winds
+        let side = |point: math::Point| (to - from).cross(point - from) * area.signum();
+
+        let input = std::mem::take(&mut clipped);
+
+        for (&current, &next) in input.iter().zip(input.iter().cycle().skip(1))
+        {
+            let (current_side, next_side) = (side(current), side(next));
+
+            if current_side >= 0.0
+            {
+                clipped.push(current);
+            }
+
+            if (current_side >= 0.0) != (next_side >= 0.0)
+            {
+                clipped.push(current.lerp(next, current_side / (current_side - next_side)));
+            }
+        }
+    }
+
+    clipped
+}
+
+/// Internal utility function that splits a contour into the polylines of its dashes.
+pub(crate) fn dash_contour(points: &[math::Point], is_closed: bool, dashes: &[f32], offset: f32) -> Vec<Vec<math::Point>>
+{
+    let mut points = points.to_vec();
+
+    if let (true, Some(&first)) = (is_closed, points.first())
+    {
+        points.push(first);
+    }
+
+    // Lengths that aren't positive are empty, and without any length left in the pattern the whole contour is a single dash
+    let dashes: Vec<f32> = dashes.iter().map(|&length| if length > 0.0 { length } else { 0.0 }).collect();
+    let pattern_length: f32 = dashes.iter().sum();
+    if pattern_length <= 0.0 || points.is_empty()
+    {
+        return vec![points];
+    }
+
+    // Skip ahead to where the pattern is at the start of the contour, even dash indices are dashes and odd ones gaps
+    let mut phase = offset.rem_euclid(pattern_length);
+    let mut index = 0;
+    while phase >= dashes[index]
+    {
+        phase -= dashes[index];
+        index = (index + 1) % dashes.len();
+    }
+    let mut remaining = dashes[index] - phase;
+
+    let mut polylines = vec![];
+    let mut current = if index % 2 == 0 { vec![points[0]] } else { vec![] };
+
+    for segment in points.windows(2)
+    {
+        let (mut from, to) = (segment[0], segment[1]);
+        let mut length = (to - from).length();
+
+        while length > remaining
+        {
+            let split = from.lerp(to, remaining / length);
+
+            if index % 2 == 0
+            {
+                current.push(split);
+                polylines.push(std::mem::take(&mut current));
+            }
+            else
+            {
+                current = vec![split];
+            }
+
+            length -= remaining;
+            from = split;
+            index = (index + 1) % dashes.len();
+            remaining = dashes[index];
+        }
+
+        remaining -= length;
+
+        if index % 2 == 0
+        {
+            current.push(to);
+        }
+    }
+
+    if current.len() > 1
+    {
+        polylines.push(current);
+    }
+
+    polylines
+}
+
+/// Internal utility function that samples a gradient made of sorted color stops.
+fn sample_gradient(stops: &[(f32, [f32; 4])], offset: f32) -> [f32; 4]
+{
+    let after = stops.iter().position(|&(stop, _)| stop > offset).unwrap_or(stops.len());
+
+    match (after.checked_sub(1).map(|before| stops[before]), stops.get(after))
+    {
+        (Some((start, from)), Some(&(end, to))) => {
+            let t = (offset - start) / (end - start);
+            let lerp = |i: usize| from[i] + (to[i] - from[i]) * t;
+
+            [lerp(0), lerp(1), lerp(2), lerp(3)]
+        },
+        (Some((_, color)), None) | (None, Some(&(_, color))) => color,
+        (None, None) => [1.0, 1.0, 1.0, 1.0],
+    }
+}
+
+/// Internal utility function that picks a fully saturated color for an index, spreading consecutive indices far apart in hue.
+pub(crate) fn debug_color(index: usize) -> [f32; 4]
+{
+    // Stepping by the golden ratio keeps any number of consecutive hues well separated
+    let hue = (index as f32 * 0.618_034).fract() * 6.0;
+    let x = 1.0 - (hue % 2.0 - 1.0).abs();
+
+    let [r, g, b] = match hue as u32
+    {
+        0 => [1.0, x, 0.0],
+        1 => [x, 1.0, 0.0],
+        2 => [0.0, 1.0, x],
+        3 => [0.0, x, 1.0],
+        4 => [x, 0.0, 1.0],
+        _ => [1.0, 0.0, x],
+    };
+
+    [r, g, b, 1.0]
+}
+
+/// Internal utility function that computes the region of the `[0, 1]` uv square given to an island,
+/// when `count` islands are laid out in a grid with `padding` around each of them.
+fn atlas_cell(index: usize, count: usize, padding: f32) -> math::Rect
+{
+    let columns = (count as f32).sqrt().ceil().max(1.0);
+    let rows = (count as f32 / columns).ceil().max(1.0);
+    let cell_size = math::size(1.0 / columns, 1.0 / rows);
+
+    let cell_origin = math::point((index as f32 % columns) * cell_size.width, (index as f32 / columns).floor() * cell_size.height);
+
+    math::Rect::new(
+        cell_origin + math::vector(padding, padding),
+        math::size(
+            (cell_size.width - 2.0 * padding).max(0.0),
+            (cell_size.height - 2.0 * padding).max(0.0)
+        )
+    )
+}
+
+/// Internal utility function that computes the 2d bounding box of some vertices.
+fn vertex_bounds<'a>(vertices: impl Iterator<Item=&'a BevyVertex>) -> math::Rect
+{
+    math::Rect::from_points(vertices.map(|vertex| math::point(vertex.pos[0], vertex.pos[1])))
+}
+
+/// Internal utility function that fits a bounding box into a cell, keeping its aspect ratio.
+///
+/// Returns the region of the cell that was used and a function mapping a vertex to its uv within it.
+fn fit_island(bounds: math::Rect, cell: math::Rect) -> (math::Rect, impl Fn(&BevyVertex) -> [f32; 2])
+{
+    let scale = (cell.size.width / bounds.size.width).min(cell.size.height / bounds.size.height);
+    let scale = if scale.is_finite() { scale } else { 0.0 };
+
+    let island = math::Rect::new(cell.origin, bounds.size * scale);
+
+    (island, move |vertex: &BevyVertex| [
+        island.origin.x + (vertex.pos[0] - bounds.origin.x) * scale,
+        island.origin.y + (vertex.pos[1] - bounds.origin.y) * scale,
+    ])
+}
+
+/// Makes a builder out of previously baked geometry, which can then be built or have more shapes added.
+impl From<BakedMesh> for LyonMeshBuilder
+{
+    fn from(baked: BakedMesh) -> Self
+    {
+        let mut builder = LyonMeshBuilder::new();
+
+        builder.geometry.vertices = baked.vertices;
+        builder.geometry.indices = baked.indices;
+        builder.shapes = baked.shapes;
+        builder
+    }
+}
+
+// Debug is implemented by hand because the level of detail shapes are closures.
+impl fmt::Debug for LyonMeshBuilder
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("LyonMeshBuilder")
+            .field("geometry", &self.geometry)
+            .field("shapes", &self.shapes)
+            .field("lightmap_uvs", &self.lightmap_uvs)
+            .field("vertex_layout", &self.vertex_layout)
+            .field("normal_generation", &self.normal_generation)
+            .field("lod_shapes", &self.lod_shapes.len())
+            .finish()
+    }
+}
+
+/// Utility type for containing the trait implementations that transforms a lyon point into a `BevyVertex`.
+pub struct BevyVertexConstructor;
+
+// TODO: Figure out if uv mapping should be specific for this
+impl tess::FillVertexConstructor<BevyVertex> for BevyVertexConstructor
+{
+    fn new_vertex(&mut self, vertex: tess::FillVertex) -> BevyVertex
+    {
+        vertex.position().into()
+    }
+}
+
+// TODO: Figure out if uv mapping should be specific for this
+impl tess::StrokeVertexConstructor<BevyVertex> for BevyVertexConstructor
+{
+    fn new_vertex(&mut self, vertex: tess::StrokeVertex) -> BevyVertex
+    {
+        vertex.position().into()
+    }
+}
+
+/// Vertex constructor giving all of the vertices of a fill the same color, such as for [`LyonMeshBuilder::with_colored_rects`].
+struct ColorVertexConstructor
+{
+    color: [f32; 4],
+}
+
+impl tess::FillVertexConstructor<BevyVertex> for ColorVertexConstructor
+{
+    fn new_vertex(&mut self, vertex: tess::FillVertex) -> BevyVertex
+    {
+        BevyVertex {
+            color: self.color,
+            ..vertex.position().into()
+        }
+    }
+}
+
+/// Vertex constructor coloring stroke vertices by how far along their contour they are, see [`LyonMeshBuilder::with_stroke_gradient`].
+struct GradientVertexConstructor<'a>
+{
+    stops: &'a [(f32, [f32; 4])],
+    length: f32,
+}
+
+impl tess::StrokeVertexConstructor<BevyVertex> for GradientVertexConstructor<'_>
+{
+    fn new_vertex(&mut self, vertex: tess::StrokeVertex) -> BevyVertex
+    {
+        let offset = if self.length > 0.0 { vertex.advancement() / self.length } else { 0.0 };
+
+        BevyVertex {
+            color: sample_gradient(self.stops, offset),
+            ..vertex.position().into()
+        }
+    }
+}
+
+/// Vertex constructor coloring stroke vertices by the subdivision depth of their point, see [`LyonMeshBuilder::with_flattening_debug`].
+struct DepthVertexConstructor<'a>
+{
+    depths: &'a [usize],
+}
+
+impl tess::StrokeVertexConstructor<BevyVertex> for DepthVertexConstructor<'_>
+{
+    fn new_vertex(&mut self, vertex: tess::StrokeVertex) -> BevyVertex
+    {
+        // Vertices between two points, such as the ones of joins, take the depth of the point they come from
+        let point = match vertex.source()
+        {
+            tess::VertexSource::Endpoint { id } => id,
+            tess::VertexSource::Edge { from, .. } => from,
+        };
+
+        BevyVertex {
+            color: debug_color(self.depths.get(point.to_usize()).copied().unwrap_or(0)),
+            ..vertex.position().into()
+        }
     }
 }
 
 /// Contains all the vertex information needed by bevy to correctly create a mesh.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
 pub struct BevyVertex
 {
     pub pos: [f32; 3],
     pub norm: [f32; 3],
     pub uv: [f32; 2],
+    pub color: [f32; 4],
 }
 
 /// Performs a trivial conversion from a lyon point into a `BevyVertex`
@@ -176,6 +1902,7 @@ impl From<math::Point> for BevyVertex
             pos: [point.x, point.y, 0.0],
             norm: [0.0, 0.0, 1.0],
             uv: [point.x, point.y],
+            color: [1.0, 1.0, 1.0, 1.0],
         }
     }
 }
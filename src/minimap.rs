@@ -0,0 +1,89 @@
+//! Minimaps, a small view of an area of the world framed somewhere else.
+//!
+//! # Overview
+//!
+//! A [`Minimap`] maps an area of the world onto a rect, such as in a corner of the screen.
+//! It builds the frame around that rect and a mask filling it, to draw the map's background or to limit what is drawn within it,
+//! and an indicator outlining where a camera's view is on the map, limited to the edges of the map.
+//!
+//! With the `bevy-0-5` feature, the plugin keeps the mesh of entities with a [`MinimapViewport`] component up to date
+//! with the indicator of a camera's view, as the camera moves and zooms and as the window is resized.
+//!
+//! [`MinimapViewport`]: crate::plugin::MinimapViewport
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::recording::ShapeCommand;
+use super::shapes::{
+    Corners,
+    FillRoundedRect,
+    StrokeRect,
+    StrokeRoundedRect,
+};
+
+/// A minimap, see the [module level documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct Minimap
+{
+    /// Where the map is drawn.
+    #[default(math::Rect::new(math::point(0.0, 0.0), math::size(200.0, 200.0)))]
+    pub rect: math::Rect,
+    /// The area of the world the map shows, which is stretched to fit the rect.
+    #[default(math::Rect::new(math::point(-1000.0, -1000.0), math::size(2000.0, 2000.0)))]
+    pub world: math::Rect,
+    #[default = 8.0]
+    pub corner_radius: f32,
+    #[default = 2.0]
+    pub border_width: f32,
+    /// The width of the outline of a view on the map.
+    #[default = 1.5]
+    pub indicator_width: f32,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Minimap
+{
+    /// Moves a point from the world to where it is on the map.
+    pub fn to_minimap(&self, point: math::Point) -> math::Point
+    {
+        let x = (point.x - self.world.min_x()) / self.world.width();
+        let y = (point.y - self.world.min_y()) / self.world.height();
+
+        math::point(self.rect.min_x() + x * self.rect.width(), self.rect.min_y() + y * self.rect.height())
+    }
+
+    /// The border around the map.
+    pub fn frame(&self) -> ShapeCommand
+    {
+        StrokeRoundedRect {
+            rect: self.rect,
+            radii: Corners::ALL.radii(self.corner_radius),
+            options: &tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.border_width),
+        }.into()
+    }
+
+    /// The inside of the map, in the shape of the inside of its frame.
+    pub fn mask(&self) -> ShapeCommand
+    {
+        FillRoundedRect {
+            rect: self.rect,
+            radii: Corners::ALL.radii(self.corner_radius),
+            options: &tess::FillOptions::tolerance(self.tolerance),
+        }.into()
+    }
+
+    /// The outline of an area of the world seen by a camera, limited to the map, or nothing if the view is entirely off the map.
+    pub fn viewport_indicator(&self, view: &math::Rect) -> Option<ShapeCommand>
+    {
+        let view = math::Rect::from_points([self.to_minimap(view.min()), self.to_minimap(view.max())]);
+
+        view.intersection(&self.rect).map(|rect| StrokeRect {
+            rect,
+            options: &tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.indicator_width),
+        }.into())
+    }
+}
@@ -0,0 +1,175 @@
+//! Building paths out of bevy's types, without going through lyon's own path API.
+//!
+//! # Overview
+//!
+//! A [`BevyPathBuilder`] draws a path one segment at a time from points given as `Vec2`s, tuples or arrays,
+//! and gives it back as a shape that [`LyonMeshBuilder::with`] takes, either filled or stroked.
+//! Paths can also be built for later with [`BevyPathBuilder::build`], such as to fill and stroke the same path.
+//!
+//! ```rust
+//! # use lyon::tessellation::FillOptions;
+//! # use bevy_lyon::{
+//! #    BevyPathBuilder,
+//! #    LyonMeshBuilder
+//! # };
+//! let options = FillOptions::DEFAULT;
+//! let mesh = LyonMeshBuilder::new()
+//!     .with(BevyPathBuilder::new()
+//!         .move_to((0.0, 0.0))
+//!         .line_to((50.0, 0.0))
+//!         .quad_to((50.0, 50.0), (0.0, 50.0))
+//!         .close()
+//!         .fill(&options))
+//!     .build();
+//! ```
+//!
+//! [`LyonMeshBuilder::with`]: crate::mesh_builder::LyonMeshBuilder::with
+
+use lyon::{
+    geom::ArcFlags,
+    path::{
+        self,
+        builder::{
+            SvgPathBuilder,
+            WithSvg,
+        },
+        path::BuilderImpl,
+    },
+    tessellation as tess,
+};
+
+use super::math;
+use super::shapes::{
+    FillPath,
+    StrokePath,
+};
+
+/// Something a [`BevyPathBuilder`] takes as a point, or as a vector for the radii of arcs.
+pub trait IntoPathPoint
+{
+    fn into_path_point(self) -> math::Point;
+}
+
+impl IntoPathPoint for math::Point
+{
+    fn into_path_point(self) -> math::Point
+    {
+        self
+    }
+}
+
+impl IntoPathPoint for (f32, f32)
+{
+    fn into_path_point(self) -> math::Point
+    {
+        math::point(self.0, self.1)
+    }
+}
+
+impl IntoPathPoint for [f32; 2]
+{
+    fn into_path_point(self) -> math::Point
+    {
+        math::point(self[0], self[1])
+    }
+}
+
+#[cfg(not(feature = "headless"))]
+impl IntoPathPoint for bevy::math::Vec2
+{
+    fn into_path_point(self) -> math::Point
+    {
+        let [x, y]: [f32; 2] = self.into();
+        math::point(x, y)
+    }
+}
+
+/// Builder of a path, see the [module level documentation](self).
+///
+/// Every segment starts where the previous one ended, and a new sub-path is started by moving to a point.
+pub struct BevyPathBuilder
+{
+    builder: WithSvg<BuilderImpl>,
+}
+
+impl BevyPathBuilder
+{
+    /// Create a new, empty path builder.
+    pub fn new() -> Self
+    {
+        BevyPathBuilder { builder: path::Path::svg_builder() }
+    }
+
+    /// Starts a new sub-path at a point, leaving the current one open.
+    pub fn move_to(mut self, to: impl IntoPathPoint) -> Self
+    {
+        self.builder.move_to(to.into_path_point());
+        self
+    }
+
+    pub fn line_to(mut self, to: impl IntoPathPoint) -> Self
+    {
+        self.builder.line_to(to.into_path_point());
+        self
+    }
+
+    /// Adds a quadratic bézier curve bending towards `ctrl`.
+    pub fn quad_to(mut self, ctrl: impl IntoPathPoint, to: impl IntoPathPoint) -> Self
+    {
+        self.builder.quadratic_bezier_to(ctrl.into_path_point(), to.into_path_point());
+        self
+    }
+
+    /// Adds a cubic bézier curve, leaving towards `ctrl1` and arriving from `ctrl2`.
+    pub fn cubic_to(mut self, ctrl1: impl IntoPathPoint, ctrl2: impl IntoPathPoint, to: impl IntoPathPoint) -> Self
+    {
+        self.builder.cubic_bezier_to(ctrl1.into_path_point(), ctrl2.into_path_point(), to.into_path_point());
+        self
+    }
+
+    /// Adds an elliptic arc of the given radii, the way SVG does: of the arcs joining the two points,
+    /// `large_arc` picks the one sweeping more than half of the ellipse and `sweep` the one going counter-clockwise.
+    pub fn arc_to(mut self, radii: impl IntoPathPoint, x_rotation: math::Angle, large_arc: bool, sweep: bool, to: impl IntoPathPoint) -> Self
+    {
+        self.builder.arc_to(
+            radii.into_path_point().to_vector(),
+            x_rotation,
+            ArcFlags { large_arc, sweep },
+            to.into_path_point(),
+        );
+        self
+    }
+
+    /// Closes the current sub-path with a line back to its start.
+    pub fn close(mut self) -> Self
+    {
+        self.builder.close();
+        self
+    }
+
+    /// Finishes the path.
+    pub fn build(self) -> path::Path
+    {
+        self.builder.build()
+    }
+
+    /// Finishes the path as a fill, ready to be added to a mesh builder.
+    pub fn fill(self, options: &tess::FillOptions) -> FillPath<'_, path::Path>
+    {
+        FillPath { path: self.build(), options }
+    }
+
+    /// Finishes the path as a stroke, ready to be added to a mesh builder.
+    pub fn stroke(self, options: &tess::StrokeOptions) -> StrokePath<'_, path::Path>
+    {
+        StrokePath { path: self.build(), options }
+    }
+}
+
+impl Default for BevyPathBuilder
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
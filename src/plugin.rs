@@ -0,0 +1,1528 @@
+//! Bevy plugin keeping the meshes of shape entities up to date.
+//!
+//! # Overview
+//!
+//! Entities with a [`LyonShape`] and a `Handle<Mesh>` get their mesh rebuilt from the shape's commands whenever the shape changes.
+//! The plugin is only available with the `bevy-0-5` feature.
+//!
+//! The [`LyonSettings`] resource holds the defaults every shape is tessellated with, changing it re-tessellates all the shapes.
+//! It also turns bevy's multisample anti-aliasing on or off.
+//!
+//! Shape entities that are also [`ResolutionSensitive`] are tessellated at a tolerance picked for the primary window's pixel density,
+//! by the [`ResolutionPolicy`] resource, and tessellated again whenever the window is resized or its scale factor changes.
+//!
+//! Shape entities can be children of a [`LyonShapeGroup`], whose style cascades down to them. A group with a `Handle<Mesh>` and a material
+//! bakes the shapes of the children sharing its material into that one mesh, placed by their transforms, while keeping them as separate entities for editing.
+//!
+//! Large shapes can be tessellated over several frames by giving an entity a [`TessellationTask`] instead of a [`LyonShape`],
+//! with a [`TessellationProgressEvent`] sent every frame the task makes progress.
+//!
+//! Entities with a [`DashedStroke`] get the dashed stroke of an outline as their mesh, with its dashes marching along the outline over time.
+//!
+//! Entities with a [`ProgressBar`] get the fill of the bar as their mesh, rebuilt whenever the bar changes,
+//! while its track is left to an entity of its own so that value changes don't tessellate it again.
+//!
+//! Entities with a [`ViewportQuad`] get a mesh covering everything a camera sees, kept up to date as the camera moves and the window is resized.
+//! Entities with a [`ViewportAnchor`] are kept at a position of a camera's view the same way, such as for vector HUD elements.
+//! Entities with a [`MinimapViewport`] get the outline of a camera's view on a minimap as their mesh, kept up to date the same way.
+//! Entities with a [`SelectionDrag`] get a [`SelectionRect`] dragged out with the mouse in a camera's view as their mesh, such as for box selection.
+//! Entities with a [`FreehandDrawing`] get a [`FreehandStroke`] drawn with the mouse in a camera's view as their mesh,
+//! which only tessellates the new points of the stroke as it grows.
+//! The optional [`LassoPlugin`] does the same for entities with a [`LassoDrag`] and a [`Lasso`],
+//! sending a [`LassoFinished`] event with the [`LassoSelectable`] entities within it once the drag ends.
+//!
+//! Shapes are tessellated once for all the entities with the same shapes and settings, their geometry being kept in the [`TessellationCache`].
+//! The [`LyonDiagnosticsPlugin`] reports how much tessellation happens every frame to bevy's diagnostics, along with the hit rate of the cache.
+//!
+//! Shapes lyon fails to tessellate send a [`TessellationFailed`] event, and leave the entity's previous mesh as it was.
+//!
+//! With the `serialization` feature enabled, [`LyonShape`] is reflected and registered with the app,
+//! so shape entities round-trip through bevy's scenes like any other entity.
+
+use bevy::{
+    app::CoreStage,
+    asset::Asset,
+    diagnostic::{
+        Diagnostic,
+        DiagnosticId,
+        Diagnostics,
+    },
+    prelude::*,
+    window::{
+        Window,
+        WindowResized,
+        WindowScaleFactorChanged,
+        Windows,
+    },
+};
+
+use smart_default::*;
+
+use lyon::tessellation::TessellationError;
+
+use lyon::tessellation as tess;
+
+// The Reflect derive refers to `bevy_reflect`, which is only reachable through bevy here
+#[cfg(feature = "serialization")]
+#[allow(unused_imports)]
+use bevy::reflect as bevy_reflect;
+
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    marker::PhantomData,
+};
+
+use super::math;
+use super::baked::BakedMesh;
+use super::freehand::FreehandStroke;
+use super::lasso::Lasso;
+use super::mesh_builder::LyonMeshBuilder;
+use super::minimap::Minimap;
+use super::progress_bar::ProgressBar;
+use super::recording::{
+    ShapeCommand,
+    ShapeOperation,
+};
+use super::selection::SelectionRect;
+use super::snapping::Snapping;
+use super::shapes::{
+    Contour,
+    FillQuad,
+    LyonShapeOutline,
+};
+use super::time_sliced::TessellationProgress;
+
+/// Plugin adding the systems that tessellate shape entities, see the [module level documentation](self).
+#[derive(Debug, Default)]
+pub struct LyonPlugin;
+
+impl Plugin for LyonPlugin
+{
+    fn build(&self, app: &mut AppBuilder)
+    {
+        #[cfg(feature = "serialization")]
+        app.register_type::<ShapeCommand>()
+            .register_type::<LyonShape>();
+
+        app.init_resource::<LyonSettings>()
+            .init_resource::<ResolutionPolicy>()
+            .init_resource::<TessellationStats>()
+            .init_resource::<TessellationCache>()
+            .add_event::<TessellationProgressEvent>()
+            .add_event::<TessellationFailed>()
+            .add_system(anti_aliasing.system())
+            .add_system(resolution_changes.system().label("resolution_changes"))
+            .add_system(shape_meshes.system().after("resolution_changes"))
+            .add_system(tessellation_tasks.system())
+            .add_system(animate_dashes.system().label("animate_dashes"))
+            .add_system(dashed_stroke_meshes.system().after("animate_dashes"))
+            .add_system(progress_bar_meshes.system())
+            .add_system(viewport_quads.system())
+            .add_system(viewport_anchors.system())
+            .add_system(minimap_viewports.system())
+            .add_system(selection_drags.system().label("selection_drags"))
+            .add_system(selection_rect_meshes.system().after("selection_drags"))
+            .add_system(freehand_drawings.system().label("freehand_drawings"))
+            .add_system(freehand_meshes.system().after("freehand_drawings"))
+            .add_system_to_stage(CoreStage::First, reset_stats.system())
+            .add_plugin(LyonMaterialPlugin::<ColorMaterial>::default())
+            .add_plugin(LyonMaterialPlugin::<StandardMaterial>::default());
+    }
+}
+
+/// Plugin making shape groups with a material of type `M` bake their children, see [`group_meshes`].
+///
+/// The [`LyonPlugin`] already adds it for `ColorMaterial` and `StandardMaterial`, custom materials need their own.
+#[derive(Debug)]
+pub struct LyonMaterialPlugin<M>(PhantomData<M>);
+
+impl<M> Default for LyonMaterialPlugin<M>
+{
+    fn default() -> Self
+    {
+        LyonMaterialPlugin(PhantomData)
+    }
+}
+
+impl<M: Asset> Plugin for LyonMaterialPlugin<M>
+{
+    fn build(&self, app: &mut AppBuilder)
+    {
+        app.add_system(group_meshes::<M>.system());
+    }
+}
+
+/// Plugin dragging out the lassos of [`LassoDrag`] entities and selecting the [`LassoSelectable`] entities within them.
+///
+/// It isn't part of the [`LyonPlugin`], so apps without lasso selection don't run its systems.
+#[derive(Debug, Default)]
+pub struct LassoPlugin;
+
+impl Plugin for LassoPlugin
+{
+    fn build(&self, app: &mut AppBuilder)
+    {
+        app.init_resource::<TessellationStats>()
+            .add_event::<LassoFinished>()
+            .add_system(lasso_drags.system().label("lasso_drags"))
+            .add_system(lasso_meshes.system().after("lasso_drags"));
+    }
+}
+
+/// Plugin registering diagnostics for the tessellation done by the [`LyonPlugin`] every frame.
+#[derive(Debug, Default)]
+pub struct LyonDiagnosticsPlugin;
+
+impl LyonDiagnosticsPlugin
+{
+    pub const SHAPES_TESSELLATED: DiagnosticId = DiagnosticId::from_u128(0x6c79_6f6e_0000_0000_0000_0000_0000_0001);
+    pub const VERTICES_GENERATED: DiagnosticId = DiagnosticId::from_u128(0x6c79_6f6e_0000_0000_0000_0000_0000_0002);
+    /// The share of the shape entities rebuilt during the frame that got their mesh from the [`TessellationCache`].
+    pub const CACHE_HIT_RATE: DiagnosticId = DiagnosticId::from_u128(0x6c79_6f6e_0000_0000_0000_0000_0000_0003);
+}
+
+impl Plugin for LyonDiagnosticsPlugin
+{
+    fn build(&self, app: &mut AppBuilder)
+    {
+        app.add_startup_system(setup_diagnostics.system())
+            .add_system_to_stage(CoreStage::Last, diagnostics.system());
+    }
+}
+
+/// Resource counting the tessellation done by the plugin during the current frame.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TessellationStats
+{
+    pub shapes: usize,
+    pub vertices: usize,
+    /// How many shape entities got their mesh from the [`TessellationCache`] instead of being tessellated.
+    pub cache_hits: usize,
+    /// How many shape entities weren't in the [`TessellationCache`], and were tessellated.
+    pub cache_misses: usize,
+}
+
+/// Resource keeping the geometry of the shapes the plugin tessellated last, so entities with the same shapes and settings aren't tessellated again,
+/// such as many copies of the same sprite or a shape switching back and forth between two states.
+///
+/// The least recently used geometry is dropped once there is more than `capacity` of it.
+#[derive(Debug, Clone, SmartDefault)]
+pub struct TessellationCache
+{
+    #[default = 64]
+    pub capacity: usize,
+    /// The shapes with the settings applied to them, and their geometry, from the most recently used.
+    entries: Vec<(Vec<ShapeCommand>, BakedMesh)>,
+    hits: usize,
+    misses: usize,
+}
+
+impl TessellationCache
+{
+    /// How many times geometry was found in the cache since it was made.
+    pub fn hits(&self) -> usize
+    {
+        self.hits
+    }
+
+    /// How many times geometry wasn't found in the cache since it was made.
+    pub fn misses(&self) -> usize
+    {
+        self.misses
+    }
+
+    /// The share of the lookups that found their geometry in the cache, or `None` before the first lookup.
+    pub fn hit_rate(&self) -> Option<f32>
+    {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f32 / lookups as f32)
+    }
+
+    /// Drops all the geometry, keeping the counts.
+    pub fn clear(&mut self)
+    {
+        self.entries.clear();
+    }
+
+    /// Internal utility method that looks up the geometry of some shapes, counting the hit or miss, and makes it the most recently used.
+    fn get(&mut self, commands: &[ShapeCommand]) -> Option<BakedMesh>
+    {
+        match self.entries.iter().position(|(cached, _)| cached.as_slice() == commands)
+        {
+            Some(i) =>
+            {
+                self.hits += 1;
+                let entry = self.entries.remove(i);
+                self.entries.insert(0, entry);
+                Some(self.entries[0].1.clone())
+            }
+            None =>
+            {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Internal utility method that adds the geometry of some shapes as the most recently used.
+    fn insert(&mut self, commands: Vec<ShapeCommand>, baked: BakedMesh)
+    {
+        self.entries.insert(0, (commands, baked));
+        self.entries.truncate(self.capacity);
+    }
+}
+
+/// Resource holding the defaults the plugin tessellates shapes with, such as a quality setting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LyonSettings
+{
+    /// Tolerance given to the shapes whose options have lyon's default tolerance, leaving the ones that picked their own as they are.
+    pub tolerance: f32,
+    /// Stroke style given to the stroke shapes that were made with lyon's default stroke options.
+    pub stroke_options: tess::StrokeOptions,
+    /// Whether meshes are drawn with multisample anti-aliasing, which sets bevy's `Msaa` resource to 4 samples, or to 1 when turned off.
+    ///
+    /// Bevy only picks the samples up for pipelines it hasn't made yet, so this is best set before anything is drawn.
+    pub anti_aliasing: bool,
+}
+
+impl Default for LyonSettings
+{
+    fn default() -> Self
+    {
+        LyonSettings {
+            tolerance: tess::FillOptions::DEFAULT_TOLERANCE,
+            stroke_options: tess::StrokeOptions::DEFAULT,
+            anti_aliasing: true,
+        }
+    }
+}
+
+impl LyonSettings
+{
+    /// Applies the settings to a shape command, before it gets tessellated.
+    pub fn apply(&self, mut command: ShapeCommand) -> ShapeCommand
+    {
+        if let Some(options) = command.fill_options_mut()
+        {
+            if options.tolerance == tess::FillOptions::DEFAULT_TOLERANCE
+            {
+                options.tolerance = self.tolerance;
+            }
+        }
+
+        if let Some(options) = command.stroke_options_mut()
+        {
+            if *options == tess::StrokeOptions::DEFAULT
+            {
+                *options = self.stroke_options;
+            }
+
+            if options.tolerance == tess::StrokeOptions::DEFAULT_TOLERANCE
+            {
+                options.tolerance = self.tolerance;
+            }
+        }
+
+        command
+    }
+}
+
+/// Component flagging a shape entity as sensitive to pixel density, such as thin strokes and small text,
+/// so it is tessellated at a tolerance picked for the window by the [`ResolutionPolicy`] instead of the one of the [`LyonSettings`].
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct ResolutionSensitive
+{
+    /// The tolerance to tessellate at, in physical pixels.
+    #[default(tess::FillOptions::DEFAULT_TOLERANCE)]
+    pub pixel_tolerance: f32,
+}
+
+/// Resource deciding the tolerance [`ResolutionSensitive`] shapes are tessellated at, given the primary window.
+#[derive(Debug, Clone, Copy)]
+pub struct ResolutionPolicy
+{
+    pub tolerance: fn(&ResolutionSensitive, &Window) -> f32,
+}
+
+impl Default for ResolutionPolicy
+{
+    /// Converts the pixel tolerance to world units, for cameras where a world unit is a logical pixel, which is bevy's default.
+    fn default() -> Self
+    {
+        ResolutionPolicy {
+            tolerance: |sensitive, window| sensitive.pixel_tolerance / window.scale_factor() as f32,
+        }
+    }
+}
+
+/// Component making an entity a group of shapes, whose style cascades to the shape entities among its children.
+///
+/// If the group has a `Handle<Mesh>` and a material, the shapes of its children sharing that material are baked into that mesh
+/// and hidden, so they are only drawn as part of the group. Children moved to another material are shown again and drawn on their own.
+#[derive(Debug, Clone, Default)]
+pub struct LyonShapeGroup
+{
+    /// Stroke style given to the children's stroke shapes that were made with lyon's default stroke options.
+    pub stroke_options: Option<tess::StrokeOptions>,
+}
+
+impl LyonShapeGroup
+{
+    /// The settings the children of the group are tessellated with.
+    pub fn settings(&self, settings: &LyonSettings) -> LyonSettings
+    {
+        LyonSettings {
+            stroke_options: self.stroke_options.unwrap_or(settings.stroke_options),
+            ..settings.clone()
+        }
+    }
+}
+
+/// Component describing the mesh of an entity as a list of shapes.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serialization", derive(Reflect))]
+#[cfg_attr(feature = "serialization", reflect(Component))]
+pub struct LyonShape
+{
+    pub commands: Vec<ShapeCommand>,
+}
+
+impl LyonShape
+{
+    /// Makes a shape component out of a single shape.
+    pub fn new(shape: impl Into<ShapeCommand>) -> Self
+    {
+        LyonShape { commands: vec![shape.into()] }
+    }
+
+    /// Adds another shape to the component.
+    pub fn with(mut self, shape: impl Into<ShapeCommand>) -> Self
+    {
+        self.commands.push(shape.into());
+        self
+    }
+
+    /// Tessellates the shapes into a mesh builder with the given settings, ready to be built.
+    pub fn builder(&self, settings: &LyonSettings) -> LyonMeshBuilder
+    {
+        self.commands.iter()
+            .map(|command| settings.apply(command.clone()))
+            .fold(LyonMeshBuilder::new(), LyonMeshBuilder::with)
+    }
+
+    /// Tessellates the shapes like [`LyonShape::builder`], but fails as soon as lyon can't tessellate one of them.
+    pub fn try_builder(&self, settings: &LyonSettings) -> Result<LyonMeshBuilder, TessellationError>
+    {
+        self.commands.iter()
+            .try_fold(LyonMeshBuilder::new(), |builder, command| builder.try_with(settings.apply(command.clone())))
+    }
+
+    /// Applies a change made to a list of commands, such as one recorded by an [`OperationLog`](crate::recording::OperationLog) or its inverse to undo it,
+    /// so the entity's mesh is rebuilt to match.
+    ///
+    /// The operation has to have been made for commands in the same state as the shape's.
+    pub fn apply(&mut self, operation: &ShapeOperation)
+    {
+        operation.apply_to(&mut self.commands);
+    }
+
+    /// The bounding box of the outlines of the shapes, in the entity's own space, or `None` if they have no points.
+    ///
+    /// Adding the entity's translation to it gives its bounds in the world for [`align`](crate::align::align) and [`distribute`](crate::align::distribute),
+    /// as long as the entity isn't rotated or scaled.
+    pub fn bounds(&self, tolerance: f32) -> Option<math::Rect>
+    {
+        self.commands.iter()
+            .filter_map(|command| command.bounds(tolerance))
+            .reduce(|all, bounds| all.union(&bounds))
+    }
+}
+
+/// Component tessellating a list of shapes over several frames, generating at most about `vertex_budget` vertices per frame.
+///
+/// Once every shape is tessellated, the entity's `Handle<Mesh>` is given the finished mesh and the task is removed.
+/// Tasks can be cancelled with [`TessellationTask::cancel`], replaced by inserting a new task, or dropped by despawning the entity,
+/// and are cancelled on their own if the entity loses its `Handle<Mesh>`. Cancelled tasks leave the entity's mesh untouched.
+#[derive(Debug, Clone)]
+pub struct TessellationTask
+{
+    pub vertex_budget: usize,
+    pending: VecDeque<ShapeCommand>,
+    baked: BakedMesh,
+    progress: TessellationProgress,
+    cancelled: bool,
+}
+
+impl TessellationTask
+{
+    /// Makes a task tessellating the shapes of a shape component.
+    pub fn new(shape: LyonShape, vertex_budget: usize) -> Self
+    {
+        TessellationTask {
+            vertex_budget,
+            progress: TessellationProgress { shapes_total: shape.commands.len(), ..Default::default() },
+            pending: shape.commands.into(),
+            baked: BakedMesh::default(),
+            cancelled: false,
+        }
+    }
+
+    /// Stops the task, dropping its remaining shapes and the geometry tessellated so far.
+    pub fn cancel(&mut self)
+    {
+        self.pending.clear();
+        self.baked = BakedMesh::default();
+        self.cancelled = true;
+    }
+
+    /// Whether the task was cancelled, in which case it's removed without giving the entity a mesh.
+    pub fn is_cancelled(&self) -> bool
+    {
+        self.cancelled
+    }
+
+    /// Whether every shape of the task has been tessellated.
+    pub fn is_finished(&self) -> bool
+    {
+        self.pending.is_empty()
+    }
+
+    /// How far along the task is.
+    pub fn progress(&self) -> TessellationProgress
+    {
+        self.progress
+    }
+
+    /// Tessellates the next few shapes, returning whether the task is finished.
+    ///
+    /// At least one shape is tessellated per step, so progress is always made.
+    /// A shape that fails to tessellate is skipped and ends the step early with its error, the next step carries on with the other shapes.
+    pub fn step(&mut self, settings: &LyonSettings) -> Result<bool, TessellationError>
+    {
+        let start = self.baked.vertices.len();
+        let mut result = Ok(());
+
+        while let Some(command) = self.pending.pop_front()
+        {
+            self.progress.shapes_completed += 1;
+
+            match LyonMeshBuilder::new().try_with(settings.apply(command))
+            {
+                Ok(builder) => self.baked.append(builder.bake()),
+                Err(error) => {
+                    result = Err(error);
+                    break;
+                },
+            }
+
+            if self.baked.vertices.len() - start >= self.vertex_budget
+            {
+                break;
+            }
+        }
+
+        self.progress.vertices = self.baked.vertices.len();
+        result.map(|_| self.is_finished())
+    }
+}
+
+/// Event sent for every frame a [`TessellationTask`] made progress.
+#[derive(Debug, Clone)]
+pub struct TessellationProgressEvent
+{
+    pub entity: Entity,
+    pub progress: TessellationProgress,
+}
+
+/// Component describing the mesh of an entity as the dashed stroke of an outline, see [`LyonMeshBuilder::with_dashed_stroke`].
+///
+/// The outline is flattened once when the component is made, so animating the dashes only redoes the dash layout and its stroke.
+#[derive(Debug, Clone)]
+pub struct DashedStroke
+{
+    pub contours: Vec<Contour>,
+    pub options: tess::StrokeOptions,
+    pub dashes: Vec<f32>,
+    pub offset: f32,
+    /// How fast `offset` changes, in units per second.
+    pub speed: f32,
+}
+
+impl DashedStroke
+{
+    /// Makes a still dashed stroke out of a shape's outline, flattened at the tolerance of the stroke options.
+    pub fn new(shape: impl LyonShapeOutline, options: tess::StrokeOptions, dashes: Vec<f32>) -> Self
+    {
+        DashedStroke {
+            contours: shape.outline(options.tolerance),
+            options,
+            dashes,
+            offset: 0.0,
+            speed: 0.0,
+        }
+    }
+
+    /// Makes the dashes march along the outline, such as for a selection's "marching ants".
+    pub fn with_speed(mut self, speed: f32) -> Self
+    {
+        self.speed = speed;
+        self
+    }
+
+    /// Tessellates the dashes into a mesh builder, ready to be built.
+    pub fn builder(&self) -> LyonMeshBuilder
+    {
+        LyonMeshBuilder::new().with_dashed_stroke(self.contours.as_slice(), &self.options, &self.dashes, self.offset)
+    }
+}
+
+/// Component giving an entity a mesh covering everything a 2d camera sees, such as for full screen fades and vignettes.
+///
+/// The mesh is made in world space and follows the camera as it moves and zooms, and as the window is resized,
+/// so the entity's own transform should only place it along the Z axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewportQuad
+{
+    /// The camera entity, with an `OrthographicProjection`.
+    pub camera: Entity,
+    /// How far the quad extends past the edges of the view.
+    pub margin: f32,
+}
+
+impl ViewportQuad
+{
+    pub fn new(camera: Entity) -> Self
+    {
+        ViewportQuad { camera, margin: 0.0 }
+    }
+}
+
+/// Where in the view of a camera a [`ViewportAnchor`] keeps its entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor
+{
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor
+{
+    /// How far along the view the anchor is, from `0.0` at the left and bottom edges to `1.0` at the right and top edges.
+    pub fn fractions(&self) -> (f32, f32)
+    {
+        match self
+        {
+            Anchor::TopLeft => (0.0, 1.0),
+            Anchor::Top => (0.5, 1.0),
+            Anchor::TopRight => (1.0, 1.0),
+            Anchor::Left => (0.0, 0.5),
+            Anchor::Center => (0.5, 0.5),
+            Anchor::Right => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 0.0),
+            Anchor::Bottom => (0.5, 0.0),
+            Anchor::BottomRight => (1.0, 0.0),
+        }
+    }
+}
+
+/// Component keeping an entity at a position of a 2d camera's view, such as a HUD element in a corner of the screen.
+///
+/// The translation of the entity's transform is updated whenever the camera moves, zooms or is resized along with its window.
+/// Its Z coordinate is left as it is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewportAnchor
+{
+    /// The camera entity, with an `OrthographicProjection`.
+    pub camera: Entity,
+    pub anchor: Anchor,
+    /// The distance kept from the edges the entity is anchored to, in pixels, ignored along the axes it is centered on.
+    pub margin: Vec2,
+}
+
+impl ViewportAnchor
+{
+    pub fn new(camera: Entity, anchor: Anchor) -> Self
+    {
+        ViewportAnchor { camera, anchor, margin: Vec2::ZERO }
+    }
+
+    pub fn with_margin(mut self, margin: Vec2) -> Self
+    {
+        self.margin = margin;
+        self
+    }
+}
+
+/// Component giving an entity the outline of what a 2d camera sees on a minimap as its mesh, see [`Minimap::viewport_indicator`].
+///
+/// The mesh is made in the space of the minimap, so the entity should be placed along with the minimap's frame.
+/// It is rebuilt whenever the camera moves, zooms or is resized along with its window, and is left empty while the view is off the map.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimapViewport
+{
+    /// The camera entity, with an `OrthographicProjection`.
+    pub camera: Entity,
+    pub minimap: Minimap,
+}
+
+impl MinimapViewport
+{
+    pub fn new(camera: Entity, minimap: Minimap) -> Self
+    {
+        MinimapViewport { camera, minimap }
+    }
+}
+
+/// Component dragging out a [`SelectionRect`] with a mouse button in the world space of a 2d camera, with the rect as the entity's mesh.
+///
+/// The entity's mesh is only the rect while the button is held, and is left empty otherwise,
+/// while the last rect dragged out is kept in `selection` for the app to select what it covers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectionDrag
+{
+    /// The camera entity, with an `OrthographicProjection`.
+    pub camera: Entity,
+    pub button: MouseButton,
+    /// The style of the rect, whose start and end are replaced by the drag.
+    pub selection: SelectionRect,
+    /// How the start and end of the drag snap, such as to a grid of the editor.
+    pub snapping: Snapping,
+    pub dragging: bool,
+}
+
+impl SelectionDrag
+{
+    pub fn new(camera: Entity) -> Self
+    {
+        SelectionDrag {
+            camera,
+            button: MouseButton::Left,
+            selection: SelectionRect::default(),
+            snapping: Snapping::default(),
+            dragging: false,
+        }
+    }
+}
+
+/// Component drawing a [`FreehandStroke`] with a mouse button in the world space of a 2d camera, with the stroke as the entity's mesh.
+///
+/// A new stroke starts every time the button is pressed, and the last one is kept as the entity's mesh once the button is released.
+/// The stroke is tessellated as it grows, the mesh only being built again from what was already tessellated.
+#[derive(Debug, Clone)]
+pub struct FreehandDrawing
+{
+    /// The camera entity, with an `OrthographicProjection`.
+    pub camera: Entity,
+    pub button: MouseButton,
+    /// The style of the stroke, whose points are replaced by the drawing.
+    pub stroke: FreehandStroke,
+    pub drawing: bool,
+    /// The stroke tessellated so far, and how many of its points that is.
+    baked: BakedMesh,
+    tessellated: usize,
+}
+
+impl FreehandDrawing
+{
+    pub fn new(camera: Entity) -> Self
+    {
+        FreehandDrawing {
+            camera,
+            button: MouseButton::Left,
+            stroke: FreehandStroke::default(),
+            drawing: false,
+            baked: BakedMesh::default(),
+            tessellated: 0,
+        }
+    }
+
+    /// Internal utility method that tessellates the points added to the stroke since the last time, telling how many vertices that made.
+    fn tessellate(&mut self) -> usize
+    {
+        let vertices = self.baked.vertices.len();
+        let builder = LyonMeshBuilder::from(std::mem::take(&mut self.baked));
+
+        // Kept baked rather than as a builder, since components have to be `Send` and `Sync`
+        self.baked = self.stroke.extend(builder, self.tessellated).bake();
+        self.tessellated = self.stroke.points().len();
+        self.baked.vertices.len() - vertices
+    }
+}
+
+/// Component dragging out a [`Lasso`] with a mouse button in the world space of a 2d camera, with the lasso as the entity's mesh.
+///
+/// The entity's mesh is only the lasso while the button is held, and is left empty otherwise,
+/// while the last lasso drawn is kept in `lasso` once a [`LassoFinished`] event has told what it selected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LassoDrag
+{
+    /// The camera entity, with an `OrthographicProjection`.
+    pub camera: Entity,
+    pub button: MouseButton,
+    /// The style of the lasso, whose points are replaced by the drag.
+    pub lasso: Lasso,
+    pub dragging: bool,
+}
+
+impl LassoDrag
+{
+    pub fn new(camera: Entity) -> Self
+    {
+        LassoDrag {
+            camera,
+            button: MouseButton::Left,
+            lasso: Lasso::default(),
+            dragging: false,
+        }
+    }
+}
+
+/// Marker component for the entities a lasso can select, by the position of their global transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LassoSelectable;
+
+/// Event sent when the drag of a lasso ends, with the selectable entities within it.
+#[derive(Debug, Clone)]
+pub struct LassoFinished
+{
+    /// The entity with the [`LassoDrag`].
+    pub lasso: Entity,
+    pub selected: Vec<Entity>,
+}
+
+/// Event sent when a shape of an entity couldn't be tessellated.
+#[derive(Debug, Clone)]
+pub struct TessellationFailed
+{
+    pub entity: Entity,
+    pub error: TessellationError,
+}
+
+/// System setting bevy's `Msaa` resource from the [`LyonSettings`] when they change.
+pub fn anti_aliasing(settings: Res<LyonSettings>, msaa: Option<ResMut<Msaa>>)
+{
+    if let (true, Some(mut msaa)) = (settings.is_changed(), msaa)
+    {
+        let samples = if settings.anti_aliasing { 4 } else { 1 };
+
+        if msaa.samples != samples
+        {
+            msaa.samples = samples;
+        }
+    }
+}
+
+/// System flagging the shapes of every [`ResolutionSensitive`] entity as changed when a window was resized or its scale factor changed,
+/// so they are tessellated again at the new resolution.
+pub fn resolution_changes(
+    mut resized: EventReader<WindowResized>,
+    mut rescaled: EventReader<WindowScaleFactorChanged>,
+    mut shapes: Query<&mut LyonShape, With<ResolutionSensitive>>,
+)
+{
+    // Both readers are drained, so the same events aren't seen again next frame
+    let resized = resized.iter().count() > 0;
+    let rescaled = rescaled.iter().count() > 0;
+
+    if !resized && !rescaled
+    {
+        return;
+    }
+
+    for mut shape in shapes.iter_mut()
+    {
+        // Borrowing the shape mutably is what marks it as changed
+        let _: &mut LyonShape = &mut shape;
+    }
+}
+
+/// System rebuilding the mesh of every shape entity whose shape changed, or of all of them when the settings changed.
+pub fn shape_meshes(
+    settings: Res<LyonSettings>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    windows: Res<Windows>,
+    policy: Res<ResolutionPolicy>,
+    mut query: Query<(Entity, &LyonShape, ChangeTrackers<LyonShape>, Option<&Parent>, Option<&ResolutionSensitive>, &mut Handle<Mesh>)>,
+    groups: Query<(&LyonShapeGroup, ChangeTrackers<LyonShapeGroup>)>,
+    mut failures: EventWriter<TessellationFailed>,
+    mut stats: ResMut<TessellationStats>,
+    mut cache: ResMut<TessellationCache>,
+)
+{
+    for (entity, shape, tracker, parent, sensitive, mut handle) in query.iter_mut()
+    {
+        let group = parent.and_then(|parent| groups.get(parent.0).ok());
+        let group_changed = group.map_or(false, |(_, group_tracker)| group_tracker.is_changed());
+
+        if !tracker.is_changed() && !settings.is_changed() && !group_changed
+        {
+            continue;
+        }
+
+        let mut shape_settings = match group
+        {
+            Some((group, _)) => group.settings(&settings),
+            None => settings.clone(),
+        };
+
+        if let (Some(sensitive), Some(window)) = (sensitive, windows.get_primary())
+        {
+            shape_settings.tolerance = (policy.tolerance)(sensitive, window);
+        }
+
+        let commands: Vec<_> = shape.commands.iter()
+            .map(|command| shape_settings.apply(command.clone()))
+            .collect();
+
+        if let Some(baked) = cache.get(&commands)
+        {
+            stats.cache_hits += 1;
+            set_mesh(&mut meshes, &mut handle, LyonMeshBuilder::from(baked).build());
+            continue;
+        }
+
+        stats.cache_misses += 1;
+
+        match commands.iter().cloned().try_fold(LyonMeshBuilder::new(), LyonMeshBuilder::try_with)
+        {
+            Ok(builder) => {
+                stats.shapes += shape.commands.len();
+                stats.vertices += builder.vertex_count();
+
+                let baked = builder.bake();
+                cache.insert(commands, baked.clone());
+                set_mesh(&mut meshes, &mut handle, LyonMeshBuilder::from(baked).build());
+            },
+            Err(error) => failures.send(TessellationFailed { entity, error }),
+        }
+    }
+}
+
+/// System baking the shapes of the children of every group with a mesh and a material of type `M` into that mesh, when any of them changed.
+///
+/// Only the children with the same material as the group are baked into it and hidden.
+/// Children with another material, of type `M` or not, are left visible so they are drawn with their own mesh,
+/// and children leaving the group, or whose group is gone, are made visible again.
+pub fn group_meshes<M: Asset>(
+    settings: Res<LyonSettings>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut groups: Query<(
+        Entity,
+        &LyonShapeGroup,
+        ChangeTrackers<LyonShapeGroup>,
+        &Children,
+        ChangeTrackers<Children>,
+        &Handle<M>,
+        ChangeTrackers<Handle<M>>,
+        &mut Handle<Mesh>,
+    )>,
+    shapes: Query<(&LyonShape, &Transform, Option<&Handle<M>>, ChangeTrackers<LyonShape>, ChangeTrackers<Transform>)>,
+    changed_materials: Query<Entity, Changed<Handle<M>>>,
+    removed_materials: RemovedComponents<Handle<M>>,
+    removed_groups: RemovedComponents<LyonShapeGroup>,
+    mut visibles: Query<&mut Visible, With<LyonShape>>,
+    mut hidden: Local<HashMap<Entity, Vec<Entity>>>,
+    mut failures: EventWriter<TessellationFailed>,
+    mut stats: ResMut<TessellationStats>,
+)
+{
+    let removed_materials: Vec<Entity> = removed_materials.iter().collect();
+
+    // Groups that stopped being groups of this material no longer hide their children
+    for entity in removed_materials.iter().copied().chain(removed_groups.iter())
+    {
+        if let Some(children) = hidden.remove(&entity)
+        {
+            show(&mut visibles, children);
+        }
+    }
+
+    for (entity, group, group_tracker, children, children_tracker, material, material_tracker, mut handle) in groups.iter_mut()
+    {
+        let children_changed = children.iter()
+            .any(|&child| {
+                changed_materials.get(child).is_ok()
+                    || removed_materials.contains(&child)
+                    || shapes.get(child).map_or(false, |(_, _, _, shape_tracker, transform_tracker)| {
+                        shape_tracker.is_changed() || transform_tracker.is_changed()
+                    })
+            });
+
+        let group_changed = group_tracker.is_changed() || children_tracker.is_changed() || material_tracker.is_changed();
+
+        if !settings.is_changed() && !group_changed && !children_changed
+        {
+            continue;
+        }
+
+        let settings = group.settings(&settings);
+        let mut baked = BakedMesh::default();
+        let mut baked_children = Vec::new();
+
+        for &child in children.iter()
+        {
+            let (shape, transform, child_material, _, _) = match shapes.get(child)
+            {
+                Ok(shape) => shape,
+                Err(_) => continue,
+            };
+
+            let in_group = child_material == Some(material);
+
+            if in_group
+            {
+                baked_children.push(child);
+            }
+
+            if let Ok(mut visible) = visibles.get_mut(child)
+            {
+                // Only touched when it has to change, so the child isn't flagged as changed every time the group is baked
+                if visible.is_visible == in_group
+                {
+                    visible.is_visible = !in_group;
+                }
+            }
+
+            if !in_group
+            {
+                continue;
+            }
+
+            // The group is still baked without the shapes that failed
+            let mut child = match shape.try_builder(&settings)
+            {
+                Ok(builder) => builder.bake(),
+                Err(error) => {
+                    failures.send(TessellationFailed { entity: child, error });
+                    continue;
+                },
+            };
+
+            child.transform_vertices(|vertex| {
+                vertex.pos = transform.mul_vec3(Vec3::from(vertex.pos)).into();
+                vertex.norm = (transform.rotation * Vec3::from(vertex.norm)).into();
+            });
+
+            stats.shapes += shape.commands.len();
+            stats.vertices += child.vertices.len();
+            baked.append(child);
+        }
+
+        // The children hidden by the last bake which aren't part of the group anymore
+        if let Some(previous) = hidden.get(&entity)
+        {
+            let left = previous.iter()
+                .copied()
+                .filter(|child| !baked_children.contains(child))
+                .collect();
+
+            show(&mut visibles, left);
+        }
+
+        hidden.insert(entity, baked_children);
+        set_mesh(&mut meshes, &mut handle, LyonMeshBuilder::from(baked).build());
+    }
+}
+
+/// Internal utility function that makes the shapes a group used to hide visible again.
+fn show(visibles: &mut Query<&mut Visible, With<LyonShape>>, children: Vec<Entity>)
+{
+    for child in children
+    {
+        // Children despawned with their group are simply skipped
+        if let Ok(mut visible) = visibles.get_mut(child)
+        {
+            if !visible.is_visible
+            {
+                visible.is_visible = true;
+            }
+        }
+    }
+}
+
+/// Internal utility function that gives an entity its new mesh.
+fn set_mesh(meshes: &mut Assets<Mesh>, handle: &mut Handle<Mesh>, mesh: Mesh)
+{
+    // Meshes are replaced in place, so every entity sharing the handle sees the new geometry
+    match meshes.get_mut(&*handle)
+    {
+        Some(existing) => *existing = mesh,
+        None => *handle = meshes.add(mesh),
+    }
+}
+
+/// Internal utility function that finds the point of the world a 2d camera shows under the cursor.
+fn cursor_to_world(cursor: Vec2, projection: &OrthographicProjection, transform: &GlobalTransform) -> math::Point
+{
+    // The cursor is in pixels from the bottom left of the window, which is where the projection's left and bottom are
+    let world = transform.mul_vec3(Vec3::new(
+        (projection.left + cursor.x) * projection.scale,
+        (projection.bottom + cursor.y) * projection.scale,
+        0.0,
+    ));
+    math::point(world.x, world.y)
+}
+
+/// System stepping every tessellation task, and giving the finished ones their mesh.
+pub fn tessellation_tasks(
+    mut commands: Commands,
+    settings: Res<LyonSettings>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut progress_events: EventWriter<TessellationProgressEvent>,
+    mut failures: EventWriter<TessellationFailed>,
+    mut stats: ResMut<TessellationStats>,
+    mut tasks: Query<(Entity, &mut TessellationTask, Option<&mut Handle<Mesh>>)>,
+)
+{
+    for (entity, mut task, handle) in tasks.iter_mut()
+    {
+        // Without a mesh to write to, the task's work would be thrown away
+        let mut handle = match handle
+        {
+            Some(handle) if !task.is_cancelled() => handle,
+            _ => {
+                commands.entity(entity).remove::<TessellationTask>();
+                continue;
+            },
+        };
+
+        let before = task.progress();
+
+        let finished = match task.step(&settings)
+        {
+            Ok(finished) => finished,
+            Err(error) => {
+                failures.send(TessellationFailed { entity, error });
+                task.is_finished()
+            },
+        };
+
+        stats.shapes += task.progress().shapes_completed - before.shapes_completed;
+        stats.vertices += task.progress().vertices - before.vertices;
+
+        progress_events.send(TessellationProgressEvent { entity, progress: task.progress() });
+
+        if finished
+        {
+            let baked = std::mem::take(&mut task.baked);
+            set_mesh(&mut meshes, &mut handle, LyonMeshBuilder::from(baked).build());
+
+            commands.entity(entity).remove::<TessellationTask>();
+        }
+    }
+}
+
+/// System starting the tessellation counts of a new frame.
+fn reset_stats(mut stats: ResMut<TessellationStats>)
+{
+    *stats = TessellationStats::default();
+}
+
+/// Startup system registering the tessellation diagnostics.
+fn setup_diagnostics(mut diagnostics: ResMut<Diagnostics>)
+{
+    diagnostics.add(Diagnostic::new(LyonDiagnosticsPlugin::SHAPES_TESSELLATED, "lyon_shapes_tessellated", 20));
+    diagnostics.add(Diagnostic::new(LyonDiagnosticsPlugin::VERTICES_GENERATED, "lyon_vertices_generated", 20));
+    diagnostics.add(Diagnostic::new(LyonDiagnosticsPlugin::CACHE_HIT_RATE, "lyon_cache_hit_rate", 20));
+}
+
+/// System reporting the tessellation done during the frame.
+fn diagnostics(mut diagnostics: ResMut<Diagnostics>, stats: Res<TessellationStats>)
+{
+    diagnostics.add_measurement(LyonDiagnosticsPlugin::SHAPES_TESSELLATED, stats.shapes as f64);
+    diagnostics.add_measurement(LyonDiagnosticsPlugin::VERTICES_GENERATED, stats.vertices as f64);
+
+    // Frames without any lookup have no rate, rather than a rate of zero
+    let lookups = stats.cache_hits + stats.cache_misses;
+    if lookups > 0
+    {
+        diagnostics.add_measurement(LyonDiagnosticsPlugin::CACHE_HIT_RATE, stats.cache_hits as f64 / lookups as f64);
+    }
+}
+
+/// System moving the dashes of every moving dashed stroke.
+pub fn animate_dashes(time: Res<Time>, mut dashed_strokes: Query<&mut DashedStroke>)
+{
+    for mut dashed_stroke in dashed_strokes.iter_mut()
+    {
+        // Still strokes aren't touched, so they aren't rebuilt every frame
+        if dashed_stroke.speed != 0.0
+        {
+            dashed_stroke.offset += dashed_stroke.speed * time.delta_seconds();
+        }
+    }
+}
+
+/// System rebuilding the mesh of every dashed stroke that changed.
+pub fn dashed_stroke_meshes(
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut dashed_strokes: Query<(&DashedStroke, &mut Handle<Mesh>), Changed<DashedStroke>>,
+    mut stats: ResMut<TessellationStats>,
+)
+{
+    for (dashed_stroke, mut handle) in dashed_strokes.iter_mut()
+    {
+        let builder = dashed_stroke.builder();
+
+        stats.shapes += 1;
+        stats.vertices += builder.vertex_count();
+        set_mesh(&mut meshes, &mut handle, builder.build());
+    }
+}
+
+/// System rebuilding the fill mesh of every progress bar that changed.
+pub fn progress_bar_meshes(
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut progress_bars: Query<(&ProgressBar, &mut Handle<Mesh>), Changed<ProgressBar>>,
+    mut stats: ResMut<TessellationStats>,
+)
+{
+    for (progress_bar, mut handle) in progress_bars.iter_mut()
+    {
+        let builder = progress_bar.fill_builder();
+
+        stats.shapes += 1;
+        stats.vertices += builder.vertex_count();
+        set_mesh(&mut meshes, &mut handle, builder.build());
+    }
+}
+
+/// System rebuilding the mesh of every viewport quad whose camera moved, zoomed or was resized along with its window.
+pub fn viewport_quads(
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut quads: Query<(&ViewportQuad, ChangeTrackers<ViewportQuad>, &mut Handle<Mesh>)>,
+    cameras: Query<(&OrthographicProjection, ChangeTrackers<OrthographicProjection>, &GlobalTransform, ChangeTrackers<GlobalTransform>)>,
+    mut stats: ResMut<TessellationStats>,
+)
+{
+    for (quad, quad_tracker, mut handle) in quads.iter_mut()
+    {
+        let (projection, projection_tracker, transform, transform_tracker) = match cameras.get(quad.camera)
+        {
+            Ok(camera) => camera,
+            Err(_) => continue,
+        };
+
+        if !quad_tracker.is_changed() && !projection_tracker.is_changed() && !transform_tracker.is_changed()
+        {
+            continue;
+        }
+
+        // The projection is kept up to date with the window's size by bevy
+        let left = projection.left * projection.scale - quad.margin;
+        let right = projection.right * projection.scale + quad.margin;
+        let bottom = projection.bottom * projection.scale - quad.margin;
+        let top = projection.top * projection.scale + quad.margin;
+
+        let corner = |x: f32, y: f32| {
+            let world = transform.mul_vec3(Vec3::new(x, y, 0.0));
+            math::point(world.x, world.y)
+        };
+
+        let mesh = LyonMeshBuilder::with_only(FillQuad {
+            points: [corner(left, bottom), corner(right, bottom), corner(right, top), corner(left, top)],
+            ..Default::default()
+        });
+
+        stats.shapes += 1;
+        stats.vertices += 4;
+        set_mesh(&mut meshes, &mut handle, mesh);
+    }
+}
+
+/// System moving every anchored entity whose camera moved, zoomed or was resized along with its window.
+pub fn viewport_anchors(
+    mut anchors: Query<(&ViewportAnchor, ChangeTrackers<ViewportAnchor>, &mut Transform)>,
+    cameras: Query<(&OrthographicProjection, ChangeTrackers<OrthographicProjection>, &GlobalTransform, ChangeTrackers<GlobalTransform>)>,
+)
+{
+    for (anchor, anchor_tracker, mut transform) in anchors.iter_mut()
+    {
+        let (projection, projection_tracker, camera, camera_tracker) = match cameras.get(anchor.camera)
+        {
+            Ok(camera) => camera,
+            Err(_) => continue,
+        };
+
+        if !anchor_tracker.is_changed() && !projection_tracker.is_changed() && !camera_tracker.is_changed()
+        {
+            continue;
+        }
+
+        // With the default projection a pixel is a unit at a scale of 1, and margins point away from the edges they are kept from
+        let (x, y) = anchor.anchor.fractions();
+        let local = Vec3::new(
+            (projection.left + (projection.right - projection.left) * x + anchor.margin.x * (1.0 - 2.0 * x)) * projection.scale,
+            (projection.bottom + (projection.top - projection.bottom) * y + anchor.margin.y * (1.0 - 2.0 * y)) * projection.scale,
+            0.0,
+        );
+
+        let world = camera.mul_vec3(local);
+        transform.translation.x = world.x;
+        transform.translation.y = world.y;
+    }
+}
+
+/// System rebuilding the mesh of every minimap viewport indicator whose camera moved, zoomed or was resized along with its window.
+pub fn minimap_viewports(
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut indicators: Query<(&MinimapViewport, ChangeTrackers<MinimapViewport>, &mut Handle<Mesh>)>,
+    cameras: Query<(&OrthographicProjection, ChangeTrackers<OrthographicProjection>, &GlobalTransform, ChangeTrackers<GlobalTransform>)>,
+    mut stats: ResMut<TessellationStats>,
+)
+{
+    for (indicator, indicator_tracker, mut handle) in indicators.iter_mut()
+    {
+        let (projection, projection_tracker, transform, transform_tracker) = match cameras.get(indicator.camera)
+        {
+            Ok(camera) => camera,
+            Err(_) => continue,
+        };
+
+        if !indicator_tracker.is_changed() && !projection_tracker.is_changed() && !transform_tracker.is_changed()
+        {
+            continue;
+        }
+
+        // The map is axis aligned, so a rotated camera's view is outlined by its bounds
+        let corners: Vec<math::Point> = [
+            (projection.left, projection.bottom),
+            (projection.right, projection.bottom),
+            (projection.right, projection.top),
+            (projection.left, projection.top),
+        ]
+            .iter()
+            .map(|&(x, y)| {
+                let world = transform.mul_vec3(Vec3::new(x * projection.scale, y * projection.scale, 0.0));
+                math::point(world.x, world.y)
+            })
+            .collect();
+
+        let builder = match indicator.minimap.viewport_indicator(&math::Rect::from_points(&corners))
+        {
+            Some(shape) => LyonMeshBuilder::new().with(shape),
+            None => LyonMeshBuilder::new(),
+        };
+
+        stats.shapes += 1;
+        stats.vertices += builder.vertex_count();
+        set_mesh(&mut meshes, &mut handle, builder.build());
+    }
+}
+
+/// System dragging out the selection rect of every selection drag from the mouse cursor over the primary window.
+pub fn selection_drags(
+    buttons: Res<Input<MouseButton>>,
+    windows: Res<Windows>,
+    mut drags: Query<&mut SelectionDrag>,
+    cameras: Query<(&OrthographicProjection, &GlobalTransform)>,
+)
+{
+    let cursor = windows.get_primary().and_then(Window::cursor_position);
+
+    for mut drag in drags.iter_mut()
+    {
+        let (projection, transform) = match cameras.get(drag.camera)
+        {
+            Ok(camera) => camera,
+            Err(_) => continue,
+        };
+
+        let point = cursor.map(|cursor| drag.snapping.snap_point(cursor_to_world(cursor, projection, transform)));
+
+        // Drags are only touched when they change, so their meshes aren't rebuilt every frame.
+        // A cursor that left the window keeps the end where it was, and releasing the button there still ends the drag
+        if buttons.just_released(drag.button) && drag.dragging
+        {
+            drag.selection.end = point.unwrap_or(drag.selection.end);
+            drag.dragging = false;
+        }
+        else if let Some(point) = point
+        {
+            if buttons.just_pressed(drag.button)
+            {
+                drag.selection.start = point;
+                drag.selection.end = point;
+                drag.dragging = true;
+            }
+            else if drag.dragging && drag.selection.end != point
+            {
+                drag.selection.end = point;
+            }
+        }
+    }
+}
+
+/// System drawing the lasso of every lasso drag from the mouse cursor over the primary window,
+/// sending a [`LassoFinished`] event for each drag that ends.
+pub fn lasso_drags(
+    buttons: Res<Input<MouseButton>>,
+    windows: Res<Windows>,
+    mut drags: Query<(Entity, &mut LassoDrag)>,
+    cameras: Query<(&OrthographicProjection, &GlobalTransform)>,
+    selectables: Query<(Entity, &GlobalTransform), With<LassoSelectable>>,
+    mut events: EventWriter<LassoFinished>,
+)
+{
+    let cursor = windows.get_primary().and_then(Window::cursor_position);
+
+    for (entity, mut drag) in drags.iter_mut()
+    {
+        let (projection, transform) = match cameras.get(drag.camera)
+        {
+            Ok(camera) => camera,
+            Err(_) => continue,
+        };
+
+        let point = cursor.map(|cursor| cursor_to_world(cursor, projection, transform));
+
+        // Same as for selection drags, and points too close to the last one are skipped before touching the drag
+        if buttons.just_released(drag.button) && drag.dragging
+        {
+            if let Some(point) = point
+            {
+                drag.lasso.push(point);
+            }
+            drag.dragging = false;
+
+            let selected = drag.lasso.select(
+                selectables.iter().map(|(selectable, transform)| (selectable, math::point(transform.translation.x, transform.translation.y)))
+            );
+            events.send(LassoFinished { lasso: entity, selected });
+        }
+        else if let Some(point) = point
+        {
+            if buttons.just_pressed(drag.button)
+            {
+                drag.lasso.clear();
+                drag.lasso.push(point);
+                drag.dragging = true;
+            }
+            else if drag.dragging && drag.lasso.accepts(point)
+            {
+                drag.lasso.push(point);
+            }
+        }
+    }
+}
+
+/// System rebuilding the mesh of every lasso drag that changed.
+pub fn lasso_meshes(
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut drags: Query<(&LassoDrag, &mut Handle<Mesh>), Changed<LassoDrag>>,
+    mut stats: ResMut<TessellationStats>,
+)
+{
+    for (drag, mut handle) in drags.iter_mut()
+    {
+        let builder = if drag.dragging { drag.lasso.builder() } else { LyonMeshBuilder::new() };
+
+        stats.shapes += 1;
+        stats.vertices += builder.vertex_count();
+        set_mesh(&mut meshes, &mut handle, builder.build());
+    }
+}
+
+/// System drawing the stroke of every freehand drawing from the mouse cursor over the primary window,
+/// tessellating the points it adds as it goes.
+pub fn freehand_drawings(
+    buttons: Res<Input<MouseButton>>,
+    windows: Res<Windows>,
+    mut drawings: Query<&mut FreehandDrawing>,
+    cameras: Query<(&OrthographicProjection, &GlobalTransform)>,
+    mut stats: ResMut<TessellationStats>,
+)
+{
+    let cursor = windows.get_primary().and_then(Window::cursor_position);
+
+    for mut drawing in drawings.iter_mut()
+    {
+        let (projection, transform) = match cameras.get(drawing.camera)
+        {
+            Ok(camera) => camera,
+            Err(_) => continue,
+        };
+
+        let point = cursor.map(|cursor| cursor_to_world(cursor, projection, transform));
+
+        // Same as for selection drags, and samples the stroke would drop as jitter are dropped before touching the drawing
+        let added = if buttons.just_released(drawing.button) && drawing.drawing
+        {
+            if let Some(point) = point
+            {
+                drawing.stroke.push(point, None);
+            }
+            drawing.drawing = false;
+            drawing.stroke.finish()
+        }
+        else if let Some(point) = point
+        {
+            if buttons.just_pressed(drawing.button)
+            {
+                drawing.stroke.clear();
+                drawing.baked = BakedMesh::default();
+                drawing.tessellated = 0;
+                drawing.drawing = true;
+                drawing.stroke.push(point, None)
+            }
+            else if drawing.drawing && drawing.stroke.accepts(point)
+            {
+                drawing.stroke.push(point, None)
+            }
+            else
+            {
+                0
+            }
+        }
+        else
+        {
+            0
+        };
+
+        if added > 0
+        {
+            stats.shapes += added;
+            stats.vertices += drawing.tessellate();
+        }
+    }
+}
+
+/// System building the mesh of every freehand drawing that changed, from what it already tessellated.
+pub fn freehand_meshes(
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut drawings: Query<(&FreehandDrawing, &mut Handle<Mesh>), Changed<FreehandDrawing>>,
+)
+{
+    for (drawing, mut handle) in drawings.iter_mut()
+    {
+        set_mesh(&mut meshes, &mut handle, LyonMeshBuilder::from(drawing.baked.clone()).build());
+    }
+}
+
+/// System rebuilding the mesh of every selection drag that changed.
+pub fn selection_rect_meshes(
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut drags: Query<(&SelectionDrag, &mut Handle<Mesh>), Changed<SelectionDrag>>,
+    mut stats: ResMut<TessellationStats>,
+)
+{
+    for (drag, mut handle) in drags.iter_mut()
+    {
+        let builder = if drag.dragging { drag.selection.builder() } else { LyonMeshBuilder::new() };
+
+        stats.shapes += 1;
+        stats.vertices += builder.vertex_count();
+        set_mesh(&mut meshes, &mut handle, builder.build());
+    }
+}
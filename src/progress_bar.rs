@@ -0,0 +1,141 @@
+//! Progress bars, a track with a fill growing along it.
+//!
+//! # Overview
+//!
+//! A [`ProgressBar`] is made of two parts: its track, which only depends on the bar's rect and style,
+//! and its fill, which covers the part of the track up to the current value.
+//! The two are built into separate meshes, so that a bar whose value changes every frame only has its fill tessellated again.
+//!
+//! Bars with rounded ends have their fill clipped to the track, so its leading edge stays straight while its trailing end follows the track's rounded end.
+//! Bars split into segments leave a gap between the segments of their fill, such as for a health bar counting hit points.
+//!
+//! With the `bevy-0-5` feature, the plugin keeps the mesh of entities with a [`ProgressBar`] component up to date with its fill,
+//! while the track can be a [`LyonShape`] of its own made from [`ProgressBar::track`].
+//!
+//! [`LyonShape`]: crate::plugin::LyonShape
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::{
+    clip_to_convex,
+    LyonMeshBuilder,
+};
+use super::recording::ShapeCommand;
+use super::shapes::{
+    Corners,
+    FillRect,
+    FillRoundedRect,
+    LyonShapeOutline,
+};
+
+/// A progress bar, see the [module level documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct ProgressBar
+{
+    /// The rect of the track, which the fill grows along from its left.
+    #[default(math::Rect::new(math::point(0.0, 0.0), math::size(100.0, 10.0)))]
+    pub rect: math::Rect,
+    /// How much of the bar is filled, from `0.0` to `1.0`.
+    pub value: f32,
+    /// Whether the ends of the track are rounded, with a radius of half its height.
+    pub rounded: bool,
+    /// How many segments the fill is split into, with a single one being an unsplit bar.
+    #[default = 1]
+    pub segments: usize,
+    /// The width of the gaps between the segments of the fill.
+    #[default = 2.0]
+    pub divider_width: f32,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl ProgressBar
+{
+    /// The track of the bar, which doesn't depend on its value.
+    pub fn track(&self) -> ShapeCommand
+    {
+        let options = tess::FillOptions::tolerance(self.tolerance);
+
+        if self.rounded
+        {
+            FillRoundedRect { rect: self.rect, radii: Corners::ALL.radii(self.rect.height() / 2.0), options: &options }.into()
+        }
+        else
+        {
+            FillRect { rect: self.rect, options: &options }.into()
+        }
+    }
+
+    /// The fill of the bar, with a shape for each of the segments that are at least partly filled.
+    pub fn fill(&self) -> Vec<ShapeCommand>
+    {
+        let options = tess::FillOptions::tolerance(self.tolerance);
+        let outline = if self.rounded
+        {
+            FillRoundedRect { rect: self.rect, radii: Corners::ALL.radii(self.rect.height() / 2.0), options: &options }
+                .outline(self.tolerance)
+                .remove(0)
+                .points
+        }
+        else
+        {
+            vec![
+                self.rect.min(),
+                math::point(self.rect.max_x(), self.rect.min_y()),
+                self.rect.max(),
+                math::point(self.rect.min_x(), self.rect.max_y()),
+            ]
+        };
+
+        let segments = self.segments.max(1);
+        let segment_width = self.rect.width() / segments as f32;
+        let filled_to = self.rect.min_x() + self.rect.width() * self.value.max(0.0).min(1.0);
+        let (bottom, top) = (self.rect.min_y(), self.rect.max_y());
+
+        (0..segments)
+            .filter_map(|i| {
+                let start = self.rect.min_x() + segment_width * i as f32 + if i > 0 { self.divider_width / 2.0 } else { 0.0 };
+                let end = self.rect.min_x() + segment_width * (i + 1) as f32 - if i + 1 < segments { self.divider_width / 2.0 } else { 0.0 };
+                let end = end.min(filled_to);
+
+                if end <= start
+                {
+                    return None;
+                }
+
+                let points = clip_to_convex(&outline, &[
+                    math::point(start, bottom),
+                    math::point(end, bottom),
+                    math::point(end, top),
+                    math::point(start, top),
+                ]);
+
+                Some(ShapeCommand::FillPolyline { points, options })
+            })
+            .collect()
+    }
+
+    /// Builds the mesh of the track.
+    pub fn build_track(&self) -> Mesh
+    {
+        LyonMeshBuilder::with_only(self.track())
+    }
+
+    /// Builds the mesh of the fill, with each of its segments as a shape of its own.
+    pub fn build_fill(&self) -> Mesh
+    {
+        self.fill_builder().build()
+    }
+
+    /// Tessellates the fill into a mesh builder, ready to be built.
+    pub fn fill_builder(&self) -> LyonMeshBuilder
+    {
+        self.fill()
+            .into_iter()
+            .fold(LyonMeshBuilder::new(), LyonMeshBuilder::with)
+    }
+}
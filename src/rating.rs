@@ -0,0 +1,129 @@
+//! Star ratings, a row of stars filled up to a score, such as for reviews and difficulty levels.
+//!
+//! # Overview
+//!
+//! A [`StarRating`] lays out a row of stars from left to right and fills them up to its value,
+//! which can stop partway through a star: that star is split along a vertical line into a filled part and an empty part.
+//! The stars are built as a single mesh with their colors in its vertices, filled and empty parts never overlapping,
+//! so a whole rating is drawn in one go whatever its value.
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::{
+    clip_to_convex,
+    LyonMeshBuilder,
+};
+use super::recording::ShapeCommand;
+use super::shapes::FillStar;
+
+/// A row of stars, see the [module level documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct StarRating
+{
+    /// The center of the leftmost star.
+    pub origin: math::Point,
+    #[default = 5]
+    pub count: usize,
+    /// How many stars are filled, such as `3.5` for three and a half stars.
+    pub value: f32,
+    #[default = 10.0]
+    pub outer_radius: f32,
+    #[default = 4.0]
+    pub inner_radius: f32,
+    /// The space between two neighbouring stars.
+    #[default = 4.0]
+    pub spacing: f32,
+    #[default([1.0, 0.8, 0.2, 1.0])]
+    pub filled_color: [f32; 4],
+    #[default([0.5, 0.5, 0.5, 0.5])]
+    pub empty_color: [f32; 4],
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl StarRating
+{
+    /// The center of a star, from the leftmost one.
+    pub fn star_center(&self, index: usize) -> math::Point
+    {
+        self.origin + math::vector(index as f32 * (self.outer_radius * 2.0 + self.spacing), 0.0)
+    }
+
+    /// The filled stars, with the part of the star the value stops in, if any, left of its split.
+    pub fn filled(&self) -> Vec<ShapeCommand>
+    {
+        (0..self.count)
+            .filter_map(|index| self.star_part(index, 0.0, self.fill_of(index)))
+            .collect()
+    }
+
+    /// The empty stars, with the part of the star the value stops in, if any, right of its split.
+    pub fn empty(&self) -> Vec<ShapeCommand>
+    {
+        (0..self.count)
+            .filter_map(|index| self.star_part(index, self.fill_of(index), 1.0))
+            .collect()
+    }
+
+    /// Tessellates the stars into a mesh builder with their colors, the filled parts of the stars first and each part as a shape of its own.
+    pub fn builder(&self) -> LyonMeshBuilder
+    {
+        let filled = self.filled().into_iter().map(|shape| (shape, self.filled_color));
+        let empty = self.empty().into_iter().map(|shape| (shape, self.empty_color));
+
+        filled.chain(empty).fold(LyonMeshBuilder::new(), |builder, (shape, color)| builder.with_colored(shape, color))
+    }
+
+    /// Builds the mesh of the stars.
+    pub fn build(&self) -> Mesh
+    {
+        self.builder().build()
+    }
+
+    /// Internal utility function that gives how much of a star is filled, from `0.0` to `1.0`.
+    fn fill_of(&self, index: usize) -> f32
+    {
+        (self.value - index as f32).max(0.0).min(1.0)
+    }
+
+    /// Internal utility function that gives the part of a star between two fractions of its width, if it isn't empty.
+    fn star_part(&self, index: usize, from: f32, to: f32) -> Option<ShapeCommand>
+    {
+        if to <= from
+        {
+            return None;
+        }
+
+        let options = tess::FillOptions::tolerance(self.tolerance);
+        let star = FillStar {
+            center: self.star_center(index),
+            points: 5,
+            inner_radius: self.inner_radius,
+            outer_radius: self.outer_radius,
+            rotation: math::Angle::degrees(90.0),
+            options: &options,
+        };
+
+        if from <= 0.0 && to >= 1.0
+        {
+            return Some(star.into());
+        }
+
+        let left = star.center.x - self.outer_radius;
+        let (start, end) = (left + from * self.outer_radius * 2.0, left + to * self.outer_radius * 2.0);
+        let (bottom, top) = (star.center.y - self.outer_radius, star.center.y + self.outer_radius);
+
+        let points = clip_to_convex(&star.corners(), &[
+            math::point(start, bottom),
+            math::point(end, bottom),
+            math::point(end, top),
+            math::point(start, top),
+        ]);
+
+        Some(ShapeCommand::FillPolyline { points, options })
+    }
+}
@@ -0,0 +1,954 @@
+//! Recording of the shapes added to a mesh builder, so they can be replayed later.
+//!
+//! # Overview
+//!
+//! The [`RecordingBuilder`] works like a [`LyonMeshBuilder`], but also keeps an owned [`ShapeCommand`] for every shape added to it.
+//! Replaying a list of commands rebuilds the exact same geometry, which is what undo/redo in drawing tools needs
+//! (drop the last commands and replay the rest) and makes tessellation issues easy to reproduce from a bug report.
+//!
+//! An [`OperationLog`] keeps a list of commands along with the changes made to it, each a [`ShapeOperation`] that knows how to take itself back,
+//! so undoing and redoing only touch the commands that changed instead of the application keeping a copy of every version of its shapes.
+//! Operations apply just as well to the commands of the plugin's shape entities, through `LyonShape::apply`,
+//! such as to undo a change made with a log kept next to the entity.
+//!
+//! With the `serialization` feature enabled, commands implement `serde`'s `Serialize` and `Deserialize`.
+//! Only the shapes of the [`shapes`](crate::shapes) module can be recorded, since closures can't be captured as data.
+//!
+//! [`LyonMeshBuilder`]: crate::mesh_builder::LyonMeshBuilder
+
+#[cfg(feature = "serialization")]
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+// The Reflect derive refers to `bevy_reflect`, which is only reachable through bevy here
+#[cfg(all(feature = "bevy-0-5", feature = "serialization"))]
+#[allow(unused_imports)]
+use bevy::reflect::{
+    self as bevy_reflect,
+    Reflect,
+};
+
+use lyon::{
+    path::builder::BorderRadii,
+    tessellation as tess,
+};
+
+use super::math;
+
+use super::mesh_builder::{
+    BevyBuffersBuilder,
+    LyonMeshBuilder,
+};
+
+use super::shapes::{
+    self,
+    Contour,
+    LyonShapeBuilder,
+    LyonShapeOutline,
+};
+
+/// An owned description of a single shape, with all of its parameters.
+///
+/// Shapes that only differ in how their points are given (such as quads and polylines) are recorded as the same command.
+/// Corner radii are stored as `[top_left, top_right, bottom_left, bottom_right]`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+#[cfg_attr(all(feature = "bevy-0-5", feature = "serialization"), derive(Reflect), reflect_value(PartialEq, Serialize, Deserialize))]
+pub enum ShapeCommand
+{
+    Callout { target: math::Point, elbow: Option<math::Point>, label: math::Rect, corner_radius: f32, line_width: f32, head_length: f32, head_width: f32, options: tess::FillOptions },
+    FillArc { center: math::Point, radius: f32, start_angle: math::Angle, sweep_angle: math::Angle, options: tess::FillOptions },
+    FillCircle { center: math::Point, radius: f32, options: tess::FillOptions },
+    FillCrescent { center: math::Point, radius: f32, cut_offset: math::Vector, cut_radius: f32, options: tess::FillOptions },
+    FillHeart { center: math::Point, size: f32, options: tess::FillOptions },
+    FillPolyline { points: Vec<math::Point>, options: tess::FillOptions },
+    FillRect { rect: math::Rect, options: tess::FillOptions },
+    FillRoundedPolygon { points: Vec<math::Point>, radius: f32, radii: Vec<f32>, options: tess::FillOptions },
+    FillRoundedRect { rect: math::Rect, radii: [f32; 4], options: tess::FillOptions },
+    FillSector { center: math::Point, radius: f32, start_angle: math::Angle, sweep_angle: math::Angle, options: tess::FillOptions },
+    FillSpline { points: Vec<math::Point>, tension: f32, options: tess::FillOptions },
+    FillSubtracted { base: Box<ShapeCommand>, cutter: Box<ShapeCommand>, options: tess::FillOptions },
+    FillSunburst { center: math::Point, count: usize, inner_radius: f32, outer_radius: f32, gap_angle: math::Angle, rotation: math::Angle, kind: shapes::SunburstKind, options: tess::FillOptions },
+    FillSuperellipse { center: math::Point, radii: math::Vector, exponent: f32, options: tess::FillOptions },
+    FillWithHoles { outer: Vec<math::Point>, holes: Vec<Vec<math::Point>>, options: tess::FillOptions },
+    Offset { shape: Box<ShapeCommand>, distance: f32, join: shapes::OffsetJoin, options: tess::FillOptions },
+    StrokeArc { center: math::Point, radius: f32, start_angle: math::Angle, sweep_angle: math::Angle, options: tess::StrokeOptions },
+    StrokeBSpline { points: Vec<math::Point>, degree: usize, knots: Option<Vec<f32>>, options: tess::StrokeOptions },
+    StrokeCircle { center: math::Point, radius: f32, options: tess::StrokeOptions },
+    StrokeEllipse { center: math::Point, radii: math::Vector, x_rotation: math::Angle, options: tess::StrokeOptions },
+    StrokePolyline { points: Vec<math::Point>, is_closed: bool, options: tess::StrokeOptions },
+    StrokeQuadraticBezier { from: math::Point, ctrl: math::Point, to: math::Point, flattening: shapes::CurveFlattening, options: tess::StrokeOptions },
+    StrokeRect { rect: math::Rect, options: tess::StrokeOptions },
+    StrokeRoundedPolygon { points: Vec<math::Point>, radius: f32, radii: Vec<f32>, is_closed: bool, options: tess::StrokeOptions },
+    StrokeRoundedRect { rect: math::Rect, radii: [f32; 4], options: tess::StrokeOptions },
+    StrokeSpiral { center: math::Point, start_radius: f32, growth: f32, turns: f32, start_angle: math::Angle, kind: shapes::SpiralKind, options: tess::StrokeOptions },
+    StrokeSpline { points: Vec<math::Point>, tension: f32, is_closed: bool, options: tess::StrokeOptions },
+    StrokeWave { from: math::Point, to: math::Point, amplitude: f32, frequency: f32, phase: math::Angle, waveform: shapes::Waveform, options: tess::StrokeOptions },
+}
+
+impl LyonShapeBuilder for ShapeCommand
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        match self
+        {
+            ShapeCommand::Callout { target, elbow, label, corner_radius, line_width, head_length, head_width, options } =>
+                shapes::Callout { target, elbow, label, corner_radius, line_width, head_length, head_width, options: &options }.try_build(builder),
+            ShapeCommand::FillArc { center, radius, start_angle, sweep_angle, options } =>
+                shapes::FillArc { center, radius, start_angle, sweep_angle, options: &options }.try_build(builder),
+            ShapeCommand::FillCircle { center, radius, options } =>
+                shapes::FillCircle { center, radius, options: &options }.try_build(builder),
+            ShapeCommand::FillCrescent { center, radius, cut_offset, cut_radius, options } =>
+                shapes::FillCrescent { center, radius, cut_offset, cut_radius, options: &options }.try_build(builder),
+            ShapeCommand::FillHeart { center, size, options } =>
+                shapes::FillHeart { center, size, options: &options }.try_build(builder),
+            ShapeCommand::FillPolyline { points, options } =>
+                shapes::FillPolyline { points, options: &options, ..Default::default() }.try_build(builder),
+            ShapeCommand::FillRect { rect, options } =>
+                shapes::FillRect { rect, options: &options }.try_build(builder),
+            ShapeCommand::FillRoundedPolygon { points, radius, radii, options } =>
+                shapes::FillRoundedPolygon { points, radius, radii, options: &options }.try_build(builder),
+            ShapeCommand::FillRoundedRect { rect, radii, options } =>
+                shapes::FillRoundedRect { rect, radii: border_radii(radii), options: &options }.try_build(builder),
+            ShapeCommand::FillSector { center, radius, start_angle, sweep_angle, options } =>
+                shapes::FillSector { center, radius, start_angle, sweep_angle, options: &options }.try_build(builder),
+            ShapeCommand::FillSpline { points, tension, options } =>
+                shapes::FillSpline { points, tension, options: &options }.try_build(builder),
+            ShapeCommand::FillSubtracted { base, cutter, options } =>
+                shapes::FillSubtracted { base: *base, cutter: *cutter, options: &options }.try_build(builder),
+            ShapeCommand::FillSunburst { center, count, inner_radius, outer_radius, gap_angle, rotation, kind, options } =>
+                shapes::FillSunburst { center, count, inner_radius, outer_radius, gap_angle, rotation, kind, options: &options }.try_build(builder),
+            ShapeCommand::FillSuperellipse { center, radii, exponent, options } =>
+                shapes::FillSuperellipse { center, radii, exponent, options: &options }.try_build(builder),
+            ShapeCommand::FillWithHoles { outer, holes, options } =>
+                shapes::FillWithHoles { outer, holes, options: &options }.try_build(builder),
+            ShapeCommand::Offset { shape, distance, join, options } =>
+                shapes::Offset { shape: *shape, distance, join, options: &options }.try_build(builder),
+            ShapeCommand::StrokeArc { center, radius, start_angle, sweep_angle, options } =>
+                shapes::StrokeArc { center, radius, start_angle, sweep_angle, options: &options }.try_build(builder),
+            ShapeCommand::StrokeBSpline { points, degree, knots, options } =>
+                shapes::StrokeBSpline { points, degree, knots, options: &options }.try_build(builder),
+            ShapeCommand::StrokeCircle { center, radius, options } =>
+                shapes::StrokeCircle { center, radius, options: &options }.try_build(builder),
+            ShapeCommand::StrokeEllipse { center, radii, x_rotation, options } =>
+                shapes::StrokeEllipse { center, radii, x_rotation, options: &options }.try_build(builder),
+            ShapeCommand::StrokePolyline { points, is_closed, options } =>
+                shapes::StrokePolyline { points, is_closed, options: &options }.try_build(builder),
+            ShapeCommand::StrokeQuadraticBezier { from, ctrl, to, flattening, options } =>
+                shapes::StrokeQuadraticBezier { from, ctrl, to, flattening, options: &options }.try_build(builder),
+            ShapeCommand::StrokeRect { rect, options } =>
+                shapes::StrokeRect { rect, options: &options }.try_build(builder),
+            ShapeCommand::StrokeRoundedPolygon { points, radius, radii, is_closed, options } =>
+                shapes::StrokeRoundedPolygon { points, radius, radii, is_closed, options: &options }.try_build(builder),
+            ShapeCommand::StrokeRoundedRect { rect, radii, options } =>
+                shapes::StrokeRoundedRect { rect, radii: border_radii(radii), options: &options }.try_build(builder),
+            ShapeCommand::StrokeSpiral { center, start_radius, growth, turns, start_angle, kind, options } =>
+                shapes::StrokeSpiral { center, start_radius, growth, turns, start_angle, kind, options: &options }.try_build(builder),
+            ShapeCommand::StrokeSpline { points, tension, is_closed, options } =>
+                shapes::StrokeSpline { points, tension, is_closed, options: &options }.try_build(builder),
+            ShapeCommand::StrokeWave { from, to, amplitude, frequency, phase, waveform, options } =>
+                shapes::StrokeWave { from, to, amplitude, frequency, phase, waveform, options: &options }.try_build(builder),
+        }
+    }
+}
+
+impl LyonShapeOutline for ShapeCommand
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        match self.clone()
+        {
+            ShapeCommand::Callout { target, elbow, label, corner_radius, line_width, head_length, head_width, options } =>
+                shapes::Callout { target, elbow, label, corner_radius, line_width, head_length, head_width, options: &options }.outline(tolerance),
+            ShapeCommand::FillArc { center, radius, start_angle, sweep_angle, options } =>
+                shapes::FillArc { center, radius, start_angle, sweep_angle, options: &options }.outline(tolerance),
+            ShapeCommand::FillCircle { center, radius, options } =>
+                shapes::FillCircle { center, radius, options: &options }.outline(tolerance),
+            ShapeCommand::FillCrescent { center, radius, cut_offset, cut_radius, options } =>
+                shapes::FillCrescent { center, radius, cut_offset, cut_radius, options: &options }.outline(tolerance),
+            ShapeCommand::FillHeart { center, size, options } =>
+                shapes::FillHeart { center, size, options: &options }.outline(tolerance),
+            ShapeCommand::FillPolyline { points, options } =>
+                shapes::FillPolyline { points, options: &options, ..Default::default() }.outline(tolerance),
+            ShapeCommand::FillRect { rect, options } =>
+                shapes::FillRect { rect, options: &options }.outline(tolerance),
+            ShapeCommand::FillRoundedPolygon { points, radius, radii, options } =>
+                shapes::FillRoundedPolygon { points, radius, radii, options: &options }.outline(tolerance),
+            ShapeCommand::FillRoundedRect { rect, radii, options } =>
+                shapes::FillRoundedRect { rect, radii: border_radii(radii), options: &options }.outline(tolerance),
+            ShapeCommand::FillSector { center, radius, start_angle, sweep_angle, options } =>
+                shapes::FillSector { center, radius, start_angle, sweep_angle, options: &options }.outline(tolerance),
+            ShapeCommand::FillSpline { points, tension, options } =>
+                shapes::FillSpline { points, tension, options: &options }.outline(tolerance),
+            ShapeCommand::FillSubtracted { base, cutter, options } =>
+                shapes::FillSubtracted { base: *base, cutter: *cutter, options: &options }.outline(tolerance),
+            ShapeCommand::FillSunburst { center, count, inner_radius, outer_radius, gap_angle, rotation, kind, options } =>
+                shapes::FillSunburst { center, count, inner_radius, outer_radius, gap_angle, rotation, kind, options: &options }.outline(tolerance),
+            ShapeCommand::FillSuperellipse { center, radii, exponent, options } =>
+                shapes::FillSuperellipse { center, radii, exponent, options: &options }.outline(tolerance),
+            ShapeCommand::FillWithHoles { outer, holes, options } =>
+                shapes::FillWithHoles { outer, holes, options: &options }.outline(tolerance),
+            ShapeCommand::Offset { shape, distance, join, options } =>
+                shapes::Offset { shape: *shape, distance, join, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeArc { center, radius, start_angle, sweep_angle, options } =>
+                shapes::StrokeArc { center, radius, start_angle, sweep_angle, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeBSpline { points, degree, knots, options } =>
+                shapes::StrokeBSpline { points, degree, knots, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeCircle { center, radius, options } =>
+                shapes::StrokeCircle { center, radius, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeEllipse { center, radii, x_rotation, options } =>
+                shapes::StrokeEllipse { center, radii, x_rotation, options: &options }.outline(tolerance),
+            ShapeCommand::StrokePolyline { points, is_closed, options } =>
+                shapes::StrokePolyline { points, is_closed, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeQuadraticBezier { from, ctrl, to, flattening, options } =>
+                shapes::StrokeQuadraticBezier { from, ctrl, to, flattening, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeRect { rect, options } =>
+                shapes::StrokeRect { rect, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeRoundedPolygon { points, radius, radii, is_closed, options } =>
+                shapes::StrokeRoundedPolygon { points, radius, radii, is_closed, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeRoundedRect { rect, radii, options } =>
+                shapes::StrokeRoundedRect { rect, radii: border_radii(radii), options: &options }.outline(tolerance),
+            ShapeCommand::StrokeSpiral { center, start_radius, growth, turns, start_angle, kind, options } =>
+                shapes::StrokeSpiral { center, start_radius, growth, turns, start_angle, kind, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeSpline { points, tension, is_closed, options } =>
+                shapes::StrokeSpline { points, tension, is_closed, options: &options }.outline(tolerance),
+            ShapeCommand::StrokeWave { from, to, amplitude, frequency, phase, waveform, options } =>
+                shapes::StrokeWave { from, to, amplitude, frequency, phase, waveform, options: &options }.outline(tolerance),
+        }
+    }
+}
+
+impl ShapeCommand
+{
+    /// The fill options of the command, if it is a fill shape.
+    pub fn fill_options_mut(&mut self) -> Option<&mut tess::FillOptions>
+    {
+        match self
+        {
+            ShapeCommand::Callout { options, .. }
+            | ShapeCommand::FillArc { options, .. }
+            | ShapeCommand::FillCircle { options, .. }
+            | ShapeCommand::FillCrescent { options, .. }
+            | ShapeCommand::FillHeart { options, .. }
+            | ShapeCommand::FillPolyline { options, .. }
+            | ShapeCommand::FillRect { options, .. }
+            | ShapeCommand::FillRoundedPolygon { options, .. }
+            | ShapeCommand::FillRoundedRect { options, .. }
+            | ShapeCommand::FillSector { options, .. }
+            | ShapeCommand::FillSpline { options, .. }
+            | ShapeCommand::FillSubtracted { options, .. }
+            | ShapeCommand::FillSunburst { options, .. }
+            | ShapeCommand::FillSuperellipse { options, .. }
+            | ShapeCommand::FillWithHoles { options, .. }
+            | ShapeCommand::Offset { options, .. } => Some(options),
+            _ => None,
+        }
+    }
+
+    /// Changes the fill rule of the command if it is a fill shape, leaving it as it is otherwise.
+    ///
+    /// This is how the fill rule of shapes already recorded is changed, such as the ones of a plugin's `LyonShape`.
+    pub fn with_fill_rule(mut self, fill_rule: tess::FillRule) -> Self
+    {
+        if let Some(options) = self.fill_options_mut()
+        {
+            options.fill_rule = fill_rule;
+        }
+
+        self
+    }
+
+    /// The stroke options of the command, if it is a stroke shape.
+    pub fn stroke_options_mut(&mut self) -> Option<&mut tess::StrokeOptions>
+    {
+        match self
+        {
+            ShapeCommand::StrokeArc { options, .. }
+            | ShapeCommand::StrokeBSpline { options, .. }
+            | ShapeCommand::StrokeCircle { options, .. }
+            | ShapeCommand::StrokeEllipse { options, .. }
+            | ShapeCommand::StrokePolyline { options, .. }
+            | ShapeCommand::StrokeQuadraticBezier { options, .. }
+            | ShapeCommand::StrokeRect { options, .. }
+            | ShapeCommand::StrokeRoundedPolygon { options, .. }
+            | ShapeCommand::StrokeRoundedRect { options, .. }
+            | ShapeCommand::StrokeSpiral { options, .. }
+            | ShapeCommand::StrokeSpline { options, .. }
+            | ShapeCommand::StrokeWave { options, .. } => Some(options),
+            _ => None,
+        }
+    }
+}
+
+/// Internal utility function that turns recorded corner radii back into lyon's type.
+fn border_radii([top_left, top_right, bottom_left, bottom_right]: [f32; 4]) -> BorderRadii
+{
+    BorderRadii { top_left, top_right, bottom_left, bottom_right }
+}
+
+/// Internal utility function that turns lyon's corner radii into their recorded form.
+fn radii_array(radii: &BorderRadii) -> [f32; 4]
+{
+    [radii.top_left, radii.top_right, radii.bottom_left, radii.bottom_right]
+}
+
+impl From<shapes::Arrow<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::Arrow) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.corners(), options: *shape.options }
+    }
+}
+
+impl From<shapes::Callout<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::Callout) -> Self
+    {
+        ShapeCommand::Callout {
+            target: shape.target,
+            elbow: shape.elbow,
+            label: shape.label,
+            corner_radius: shape.corner_radius,
+            line_width: shape.line_width,
+            head_length: shape.head_length,
+            head_width: shape.head_width,
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::FillArc<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillArc) -> Self
+    {
+        ShapeCommand::FillArc {
+            center: shape.center,
+            radius: shape.radius,
+            start_angle: shape.start_angle,
+            sweep_angle: shape.sweep_angle,
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::FillChordSegment<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillChordSegment) -> Self
+    {
+        ShapeCommand::FillArc {
+            center: shape.center,
+            radius: shape.radius,
+            start_angle: shape.start_angle,
+            sweep_angle: shape.sweep_angle(),
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::FillCircle<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillCircle) -> Self
+    {
+        ShapeCommand::FillCircle { center: shape.center, radius: shape.radius, options: *shape.options }
+    }
+}
+
+impl From<shapes::FillCrescent<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillCrescent) -> Self
+    {
+        ShapeCommand::FillCrescent {
+            center: shape.center,
+            radius: shape.radius,
+            cut_offset: shape.cut_offset,
+            cut_radius: shape.cut_radius,
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::FillDiamond<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillDiamond) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.corners().to_vec(), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillHeart<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillHeart) -> Self
+    {
+        ShapeCommand::FillHeart { center: shape.center, size: shape.size, options: *shape.options }
+    }
+}
+
+impl<I, G> From<shapes::FillConvexPolyline<'_, I, G>> for ShapeCommand
+where
+    I: IntoIterator<Item=math::Point, IntoIter=G> + Default,
+    G: Iterator<Item=math::Point> + Clone
+{
+    fn from(shape: shapes::FillConvexPolyline<I, G>) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.points.into_iter().collect(), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillParallelogram<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillParallelogram) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.corners().to_vec(), options: *shape.options }
+    }
+}
+
+impl<I> From<shapes::FillPolyline<'_, I>> for ShapeCommand
+where
+    I: IntoIterator<Item=math::Point> + Default
+{
+    fn from(shape: shapes::FillPolyline<I>) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.points.into_iter().collect(), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillQuad<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillQuad) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.points.to_vec(), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillRect<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillRect) -> Self
+    {
+        ShapeCommand::FillRect { rect: shape.rect, options: *shape.options }
+    }
+}
+
+impl From<shapes::FillRegularPolygon<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillRegularPolygon) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.points(), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillRotatedRect<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillRotatedRect) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.corners().to_vec(), options: *shape.options }
+    }
+}
+
+impl<I> From<shapes::FillRoundedPolygon<'_, I>> for ShapeCommand
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn from(shape: shapes::FillRoundedPolygon<I>) -> Self
+    {
+        ShapeCommand::FillRoundedPolygon {
+            points: shape.points.as_ref().to_vec(),
+            radius: shape.radius,
+            radii: shape.radii,
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::FillRoundedRect<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillRoundedRect) -> Self
+    {
+        ShapeCommand::FillRoundedRect { rect: shape.rect, radii: radii_array(&shape.radii), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillSector<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillSector) -> Self
+    {
+        ShapeCommand::FillSector {
+            center: shape.center,
+            radius: shape.radius,
+            start_angle: shape.start_angle,
+            sweep_angle: shape.sweep_angle,
+            options: *shape.options,
+        }
+    }
+}
+
+impl<I> From<shapes::FillSpline<'_, I>> for ShapeCommand
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn from(shape: shapes::FillSpline<I>) -> Self
+    {
+        ShapeCommand::FillSpline { points: shape.points.as_ref().to_vec(), tension: shape.tension, options: *shape.options }
+    }
+}
+
+impl<B, C> From<shapes::FillSubtracted<'_, B, C>> for ShapeCommand
+where
+    B: LyonShapeOutline + Into<ShapeCommand>,
+    C: LyonShapeOutline + Into<ShapeCommand>
+{
+    fn from(shape: shapes::FillSubtracted<B, C>) -> Self
+    {
+        ShapeCommand::FillSubtracted { base: Box::new(shape.base.into()), cutter: Box::new(shape.cutter.into()), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillSunburst<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillSunburst) -> Self
+    {
+        ShapeCommand::FillSunburst {
+            center: shape.center,
+            count: shape.count,
+            inner_radius: shape.inner_radius,
+            outer_radius: shape.outer_radius,
+            gap_angle: shape.gap_angle,
+            rotation: shape.rotation,
+            kind: shape.kind,
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::FillSuperellipse<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillSuperellipse) -> Self
+    {
+        ShapeCommand::FillSuperellipse { center: shape.center, radii: shape.radii, exponent: shape.exponent, options: *shape.options }
+    }
+}
+
+impl From<shapes::FillStar<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillStar) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.corners(), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillTriangle<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillTriangle) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.points.to_vec(), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillTrapezoid<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillTrapezoid) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.corners().to_vec(), options: *shape.options }
+    }
+}
+
+impl From<shapes::FillWithHoles<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::FillWithHoles) -> Self
+    {
+        ShapeCommand::FillWithHoles { outer: shape.outer, holes: shape.holes, options: *shape.options }
+    }
+}
+
+impl From<shapes::Hexagon<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::Hexagon) -> Self
+    {
+        ShapeCommand::FillPolyline { points: shape.corners(), options: *shape.options }
+    }
+}
+
+impl<S> From<shapes::Offset<'_, S>> for ShapeCommand
+where
+    S: LyonShapeOutline + Into<ShapeCommand>
+{
+    fn from(shape: shapes::Offset<S>) -> Self
+    {
+        ShapeCommand::Offset { shape: Box::new(shape.shape.into()), distance: shape.distance, join: shape.join, options: *shape.options }
+    }
+}
+
+impl From<shapes::StrokeArc<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeArc) -> Self
+    {
+        ShapeCommand::StrokeArc {
+            center: shape.center,
+            radius: shape.radius,
+            start_angle: shape.start_angle,
+            sweep_angle: shape.sweep_angle,
+            options: *shape.options,
+        }
+    }
+}
+
+impl<I> From<shapes::StrokeBSpline<'_, I>> for ShapeCommand
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn from(shape: shapes::StrokeBSpline<I>) -> Self
+    {
+        ShapeCommand::StrokeBSpline { points: shape.points.as_ref().to_vec(), degree: shape.degree, knots: shape.knots, options: *shape.options }
+    }
+}
+
+impl From<shapes::StrokeCircle<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeCircle) -> Self
+    {
+        ShapeCommand::StrokeCircle { center: shape.center, radius: shape.radius, options: *shape.options }
+    }
+}
+
+impl From<shapes::StrokeEllipse<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeEllipse) -> Self
+    {
+        ShapeCommand::StrokeEllipse {
+            center: shape.center,
+            radii: shape.radii,
+            x_rotation: shape.x_rotation,
+            options: *shape.options,
+        }
+    }
+}
+
+impl<I> From<shapes::StrokePolyline<'_, I>> for ShapeCommand
+where
+    I: IntoIterator<Item=math::Point> + Default
+{
+    fn from(shape: shapes::StrokePolyline<I>) -> Self
+    {
+        ShapeCommand::StrokePolyline {
+            points: shape.points.into_iter().collect(),
+            is_closed: shape.is_closed,
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::StrokeQuad<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeQuad) -> Self
+    {
+        ShapeCommand::StrokePolyline { points: shape.points.to_vec(), is_closed: shape.is_closed, options: *shape.options }
+    }
+}
+
+impl From<shapes::StrokeQuadraticBezier<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeQuadraticBezier) -> Self
+    {
+        ShapeCommand::StrokeQuadraticBezier {
+            from: shape.from,
+            ctrl: shape.ctrl,
+            to: shape.to,
+            flattening: shape.flattening,
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::StrokeRect<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeRect) -> Self
+    {
+        ShapeCommand::StrokeRect { rect: shape.rect, options: *shape.options }
+    }
+}
+
+impl From<shapes::StrokeRegularPolygon<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeRegularPolygon) -> Self
+    {
+        ShapeCommand::StrokePolyline { points: shape.points(), is_closed: true, options: *shape.options }
+    }
+}
+
+impl From<shapes::StrokeRotatedRect<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeRotatedRect) -> Self
+    {
+        ShapeCommand::StrokePolyline { points: shape.corners().to_vec(), is_closed: true, options: *shape.options }
+    }
+}
+
+impl<I> From<shapes::StrokeRoundedPolygon<'_, I>> for ShapeCommand
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn from(shape: shapes::StrokeRoundedPolygon<I>) -> Self
+    {
+        ShapeCommand::StrokeRoundedPolygon {
+            points: shape.points.as_ref().to_vec(),
+            radius: shape.radius,
+            radii: shape.radii,
+            is_closed: shape.is_closed,
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::StrokeRoundedRect<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeRoundedRect) -> Self
+    {
+        ShapeCommand::StrokeRoundedRect { rect: shape.rect, radii: radii_array(&shape.radii), options: *shape.options }
+    }
+}
+
+impl From<shapes::StrokeSpiral<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeSpiral) -> Self
+    {
+        ShapeCommand::StrokeSpiral {
+            center: shape.center,
+            start_radius: shape.start_radius,
+            growth: shape.growth,
+            turns: shape.turns,
+            start_angle: shape.start_angle,
+            kind: shape.kind,
+            options: *shape.options,
+        }
+    }
+}
+
+impl<I> From<shapes::StrokeSpline<'_, I>> for ShapeCommand
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn from(shape: shapes::StrokeSpline<I>) -> Self
+    {
+        ShapeCommand::StrokeSpline {
+            points: shape.points.as_ref().to_vec(),
+            tension: shape.tension,
+            is_closed: shape.is_closed,
+            options: *shape.options,
+        }
+    }
+}
+
+impl From<shapes::StrokeStar<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeStar) -> Self
+    {
+        ShapeCommand::StrokePolyline { points: shape.corners(), is_closed: true, options: *shape.options }
+    }
+}
+
+impl From<shapes::StrokeTriangle<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeTriangle) -> Self
+    {
+        ShapeCommand::StrokePolyline { points: shape.points.to_vec(), is_closed: shape.is_closed, options: *shape.options }
+    }
+}
+
+impl From<shapes::StrokeWave<'_>> for ShapeCommand
+{
+    fn from(shape: shapes::StrokeWave) -> Self
+    {
+        ShapeCommand::StrokeWave {
+            from: shape.from,
+            to: shape.to,
+            amplitude: shape.amplitude,
+            frequency: shape.frequency,
+            phase: shape.phase,
+            waveform: shape.waveform,
+            options: *shape.options,
+        }
+    }
+}
+
+/// Mesh builder that records every shape added to it, see the [module level documentation](self).
+#[derive(Debug, Clone)]
+pub struct RecordingBuilder
+{
+    builder: LyonMeshBuilder,
+    commands: Vec<ShapeCommand>,
+}
+
+impl RecordingBuilder
+{
+    /// Create a new recording builder, adding its shapes on top of the ones already in `builder`.
+    ///
+    /// The shapes already in `builder` aren't part of the recording.
+    pub fn new(builder: LyonMeshBuilder) -> Self
+    {
+        RecordingBuilder {
+            builder,
+            commands: vec![],
+        }
+    }
+
+    /// Makes a new recording builder that starts by replaying a list of commands, which are part of its recording.
+    pub fn replay(commands: impl IntoIterator<Item=ShapeCommand>) -> Self
+    {
+        commands.into_iter()
+            .fold(RecordingBuilder::new(LyonMeshBuilder::new()), |builder, command| builder.with(command))
+    }
+
+    /// Records a shape and adds it to the mesh being constructed.
+    pub fn with(mut self, shape: impl Into<ShapeCommand>) -> Self
+    {
+        let command = shape.into();
+
+        self.builder = self.builder.with(command.clone());
+        self.commands.push(command);
+        self
+    }
+
+    /// The commands recorded so far, in the order the shapes were added.
+    pub fn commands(&self) -> &[ShapeCommand]
+    {
+        &self.commands
+    }
+
+    /// Splits the recording builder into the mesh builder, ready to be built, and the commands it recorded.
+    pub fn into_parts(self) -> (LyonMeshBuilder, Vec<ShapeCommand>)
+    {
+        (self.builder, self.commands)
+    }
+}
+
+/// A change to a list of shape commands: the commands from `index` on that were `removed`, and the ones `inserted` in their place.
+///
+/// Every change can be written this way, adding shapes being one that removes nothing, and it is taken back by swapping the two lists.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct ShapeOperation
+{
+    pub index: usize,
+    pub removed: Vec<ShapeCommand>,
+    pub inserted: Vec<ShapeCommand>,
+}
+
+impl ShapeOperation
+{
+    /// The operation taking this one back.
+    pub fn inverse(&self) -> ShapeOperation
+    {
+        ShapeOperation { index: self.index, removed: self.inserted.clone(), inserted: self.removed.clone() }
+    }
+
+    /// Applies the operation to a list of commands, which must be the list it was made for, in the state it was made in.
+    ///
+    /// Applied to a shorter list, it only removes the commands the list has and inserts at its end at most, instead of panicking.
+    pub fn apply_to(&self, commands: &mut Vec<ShapeCommand>)
+    {
+        let start = self.index.min(commands.len());
+        let end = (self.index + self.removed.len()).min(commands.len());
+        commands.splice(start..end, self.inserted.iter().cloned());
+    }
+}
+
+/// A list of shape commands along with the operations that changed it, which can be undone and redone, see the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OperationLog
+{
+    commands: Vec<ShapeCommand>,
+    done: Vec<ShapeOperation>,
+    undone: Vec<ShapeOperation>,
+}
+
+impl OperationLog
+{
+    /// Starts a log from a list of commands, which can't be undone.
+    pub fn new(commands: Vec<ShapeCommand>) -> Self
+    {
+        OperationLog { commands, ..Default::default() }
+    }
+
+    /// The commands as they are after the operations done so far.
+    pub fn commands(&self) -> &[ShapeCommand]
+    {
+        &self.commands
+    }
+
+    /// The operations that can be undone, from the first one done.
+    pub fn history(&self) -> &[ShapeOperation]
+    {
+        &self.done
+    }
+
+    /// Applies an operation and records it, forgetting the operations that were undone before it.
+    pub fn apply(&mut self, operation: ShapeOperation) -> &ShapeOperation
+    {
+        operation.apply_to(&mut self.commands);
+        self.undone.clear();
+        self.done.push(operation);
+        &self.done[self.done.len() - 1]
+    }
+
+    /// Adds a shape after the others.
+    pub fn add(&mut self, shape: impl Into<ShapeCommand>) -> &ShapeOperation
+    {
+        self.apply(ShapeOperation { index: self.commands.len(), removed: vec![], inserted: vec![shape.into()] })
+    }
+
+    /// Replaces a range of the commands with others, which may be more or less of them.
+    ///
+    /// Nothing is done or recorded if the range isn't within the commands.
+    pub fn replace(&mut self, range: std::ops::Range<usize>, shapes: impl IntoIterator<Item=ShapeCommand>) -> Option<&ShapeOperation>
+    {
+        let removed = self.commands.get(range.clone())?.to_vec();
+        Some(self.apply(ShapeOperation { index: range.start, removed, inserted: shapes.into_iter().collect() }))
+    }
+
+    /// Removes a command, if there is one at `index`.
+    pub fn remove(&mut self, index: usize) -> Option<&ShapeOperation>
+    {
+        self.replace(index..index + 1, None)
+    }
+
+    /// Cuts the outline of a shape out of the fill of the command at `index`, if there is one, see [`LyonMeshBuilder::with_subtracted`].
+    pub fn subtract(&mut self, index: usize, cutter: impl Into<ShapeCommand>, options: &tess::FillOptions) -> Option<&ShapeOperation>
+    {
+        let base = self.commands.get(index)?.clone();
+        let subtracted = ShapeCommand::FillSubtracted { base: Box::new(base), cutter: Box::new(cutter.into()), options: *options };
+
+        self.replace(index..index + 1, Some(subtracted))
+    }
+
+    /// Takes back the last operation done, if there is one.
+    pub fn undo(&mut self) -> Option<&ShapeOperation>
+    {
+        let operation = self.done.pop()?;
+        operation.inverse().apply_to(&mut self.commands);
+        self.undone.push(operation);
+        self.undone.last()
+    }
+
+    /// Does again the last operation undone, if no other operation was done since.
+    pub fn redo(&mut self) -> Option<&ShapeOperation>
+    {
+        let operation = self.undone.pop()?;
+        operation.apply_to(&mut self.commands);
+        self.done.push(operation);
+        self.done.last()
+    }
+
+    /// Tessellates the commands into a mesh builder, ready to be built.
+    pub fn builder(&self) -> LyonMeshBuilder
+    {
+        self.commands.iter()
+            .cloned()
+            .fold(LyonMeshBuilder::new(), LyonMeshBuilder::with)
+    }
+}
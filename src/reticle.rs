@@ -0,0 +1,139 @@
+//! Reticles, the crosshairs of shooters and other aiming HUDs.
+//!
+//! # Overview
+//!
+//! A [`Reticle`] is made of arms pointing at its center from around a gap, an optional dot at its center and an optional ring around it.
+//! Its spread pushes the arms outwards, such as to show the inaccuracy of a weapon while it is moving or firing.
+//!
+//! The spread changes every frame, so the reticle is tessellated once into a builder from [`Reticle::builder`],
+//! which [`Reticle::set_spread`] updates in place by moving only the vertices of the arms, without tessellating anything again.
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::LyonMeshBuilder;
+use super::recording::ShapeCommand;
+use super::shapes::{
+    FillCircle,
+    FillRotatedRect,
+    StrokeCircle,
+};
+
+/// A reticle, see the [module level documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct Reticle
+{
+    pub center: math::Point,
+    /// How many arms there are, spread evenly around the center.
+    #[default = 4]
+    pub arms: usize,
+    /// The angle of the first arm, the others following counter-clockwise.
+    pub rotation: math::Angle,
+    /// The distance between the center and the inner ends of the arms, without any spread.
+    #[default = 4.0]
+    pub gap: f32,
+    #[default = 8.0]
+    pub arm_length: f32,
+    #[default = 2.0]
+    pub thickness: f32,
+    /// How much further out the arms are pushed on top of the gap.
+    pub spread: f32,
+    /// The radius of the dot at the center, if there is one.
+    #[default(Some(1.5))]
+    pub center_dot: Option<f32>,
+    /// The radius of the ring around the center, if there is one.
+    pub ring: Option<f32>,
+    #[default = 1.5]
+    pub ring_width: f32,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Reticle
+{
+    /// The parts of the reticle that don't depend on its spread, the center dot then the ring.
+    pub fn fixed_shapes(&self) -> Vec<ShapeCommand>
+    {
+        let fill = tess::FillOptions::tolerance(self.tolerance);
+        let stroke = tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.ring_width);
+
+        let dot = self.center_dot.map(|radius| FillCircle { center: self.center, radius, options: &fill }.into());
+        let ring = self.ring.map(|radius| StrokeCircle { center: self.center, radius, options: &stroke }.into());
+
+        dot.into_iter().chain(ring).collect()
+    }
+
+    /// The arms, counter-clockwise from the first one.
+    pub fn arm_shapes(&self) -> Vec<ShapeCommand>
+    {
+        let options = tess::FillOptions::tolerance(self.tolerance);
+        let distance = self.gap + self.spread + self.arm_length / 2.0;
+
+        (0..self.arms)
+            .map(|i| {
+                let angle = self.arm_angle(i);
+                let center = self.center + math::vector(angle.radians.cos(), angle.radians.sin()) * distance;
+
+                FillRotatedRect { center, size: math::size(self.arm_length, self.thickness), angle, options: &options }.into()
+            })
+            .collect()
+    }
+
+    /// Tessellates the reticle into a mesh builder, its fixed parts then its arms, each as a shape of its own.
+    ///
+    /// The builder is meant to be kept and updated with [`Reticle::set_spread`] when only the spread changes.
+    pub fn builder(&self) -> LyonMeshBuilder
+    {
+        self.fixed_shapes()
+            .into_iter()
+            .chain(self.arm_shapes())
+            .fold(LyonMeshBuilder::new(), LyonMeshBuilder::with)
+    }
+
+    /// Changes the spread, moving the arms of a builder from [`Reticle::builder`] to match.
+    ///
+    /// Only the vertices of the arms are touched, and only when the spread actually changed,
+    /// so the builder has to have been made from this reticle, or from one only differing from it by its spread.
+    pub fn set_spread(&mut self, builder: &mut LyonMeshBuilder, spread: f32)
+    {
+        let offset = spread - self.spread;
+
+        if offset == 0.0
+        {
+            return;
+        }
+
+        let fixed = self.center_dot.iter().count() + self.ring.iter().count();
+
+        for i in 0..self.arms
+        {
+            let angle = self.arm_angle(i);
+            let (x, y) = (angle.radians.cos() * offset, angle.radians.sin() * offset);
+
+            // The uvs of flat shapes are their positions, so they move along
+            builder.transform_shape_vertices(fixed + i, |vertex| {
+                vertex.pos[0] += x;
+                vertex.pos[1] += y;
+                vertex.uv[0] += x;
+                vertex.uv[1] += y;
+            });
+        }
+
+        self.spread = spread;
+    }
+
+    /// Builds the mesh of the whole reticle.
+    pub fn build(&self) -> Mesh
+    {
+        self.builder().build()
+    }
+
+    /// Internal utility method that gives the angle an arm points away from the center at.
+    fn arm_angle(&self, arm: usize) -> math::Angle
+    {
+        self.rotation + math::Angle::radians(std::f32::consts::PI * 2.0 * arm as f32 / self.arms as f32)
+    }
+}
@@ -0,0 +1,86 @@
+//! Selection rects, the dashed boxes dragged out to select many things at once in editors.
+//!
+//! # Overview
+//!
+//! A [`SelectionRect`] spans from where a drag started to where it is now, in any direction.
+//! It is built as a single mesh with its colors in its vertices: a translucent fill, with a dashed border over it.
+//!
+//! With the `bevy-0-5` feature, the plugin drags out the selection rect of entities with a [`SelectionDrag`] component from mouse input.
+//!
+//! [`SelectionDrag`]: crate::plugin::SelectionDrag
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::{
+    dash_contour,
+    LyonMeshBuilder,
+};
+use super::shapes::{
+    FillRect,
+    StrokePolyline,
+};
+
+/// A selection rect, see the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct SelectionRect
+{
+    /// Where the drag started.
+    pub start: math::Point,
+    /// Where the drag is now.
+    pub end: math::Point,
+    /// The lengths of the dashes and gaps of the border, see [`LyonMeshBuilder::with_dashed_stroke`].
+    #[default(vec![4.0, 4.0])]
+    pub dashes: Vec<f32>,
+    /// How far into the dash pattern the border starts, which marches the dashes around it as it changes.
+    pub dash_offset: f32,
+    #[default = 1.0]
+    pub border_width: f32,
+    #[default([1.0, 1.0, 1.0, 1.0])]
+    pub border_color: [f32; 4],
+    #[default([0.3, 0.6, 1.0, 0.2])]
+    pub fill_color: [f32; 4],
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl SelectionRect
+{
+    /// Makes a selection rect from a drag.
+    pub fn new(start: math::Point, end: math::Point) -> Self
+    {
+        SelectionRect { start, end, ..Default::default() }
+    }
+
+    /// The area selected, whichever way the drag went.
+    pub fn rect(&self) -> math::Rect
+    {
+        math::Rect::from_points([self.start, self.end])
+    }
+
+    /// Tessellates the selection rect into a mesh builder, its fill then each dash of its border as a shape of its own.
+    pub fn builder(&self) -> LyonMeshBuilder
+    {
+        let rect = self.rect();
+        let fill = tess::FillOptions::tolerance(self.tolerance);
+        let border = tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.border_width);
+
+        let corners = [rect.min(), math::point(rect.max_x(), rect.min_y()), rect.max(), math::point(rect.min_x(), rect.max_y())];
+        let builder = LyonMeshBuilder::new().with_colored(FillRect { rect, options: &fill }, self.fill_color);
+
+        dash_contour(&corners, true, &self.dashes, self.dash_offset)
+            .into_iter()
+            .fold(builder, |builder, dash| {
+                builder.with_colored(StrokePolyline { points: dash, is_closed: false, options: &border }, self.border_color)
+            })
+    }
+
+    /// Builds the mesh of the selection rect.
+    pub fn build(&self) -> Mesh
+    {
+        self.builder().build()
+    }
+}
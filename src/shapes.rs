@@ -4,25 +4,69 @@
 //! 
 //! This module provides a set of shapes consumable by the [`LyonMeshBuilder`] which draws some simple basic shapes.
 //! The shapes provided here match with the shapes that have simple tesselators provided by `lyon`.
+//! Any other path built with `lyon` can be filled or stroked through [`FillPath`] and [`StrokePath`].
+//!
+//! Fill shapes are filled with the [`FillRule`](tess::FillRule) of their options, which is even-odd in lyon's default options.
+//! It only matters for shapes whose outline crosses itself, such as a path or polyline drawing a star in a single stroke,
+//! where even-odd leaves the middle empty and non-zero fills it. Shapes made of holes always cut them out with even-odd.
 //! 
 //! [`LyonMeshBuilder`]: crate::mesh_builder::LyonMeshBuilder
 
+#[cfg(feature = "serialization")]
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
 use smart_default::*;
 
+use std::borrow::Borrow;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
 use lyon::{
-    math,
-    tessellation::{
-        self as tess,
-        basic_shapes,
+    geom::{
+        Arc,
+        ArcFlags,
+        QuadraticBezierSegment,
+    },
+    path::{
+        self,
+        builder::{
+            BorderRadii,
+            SvgPathBuilder,
+        },
+        iterator::PathIterator,
+        Polygon,
+        Winding,
     },
+    tessellation as tess,
 };
 
-use super::mesh_builder::BevyBuffersBuilder;
+use super::math;
+use super::mesh_builder::{
+    clip_to_convex,
+    is_convex,
+    BevyBuffersBuilder,
+};
 
 /// Represents something capable of being built into a shape with the [`LyonMeshBuilder`](crate::mesh_builder::LyonMeshBuilder).
 pub trait LyonShapeBuilder
 {
     fn build(self, builder: &mut BevyBuffersBuilder);
+
+    /// Builds the shape, reporting whether lyon failed to tessellate it.
+    ///
+    /// Defaults to [`LyonShapeBuilder::build`], for shapes that can't fail.
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    where
+        Self: Sized
+    {
+        self.build(builder);
+        Ok(())
+    }
 }
 
 /// Allow all closures and functions that take in a mutable reference to a [`BevyBuffersBuilder`] to be considered a shape builder.
@@ -36,308 +80,3608 @@ where
     }
 }
 
-#[derive(Debug, SmartDefault)]
-pub struct FillCircle<'a> {
-    pub center: math::Point,
-    #[default = 25.0]
-    pub radius: f32,
-    #[default(&tess::FillOptions::DEFAULT)]
-    pub options: &'a tess::FillOptions
+/// A single flattened contour of a shape's outline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contour
+{
+    pub points: Vec<math::Point>,
+    pub is_closed: bool,
 }
 
-impl LyonShapeBuilder for FillCircle<'_>
+impl Contour
 {
-    fn build(self, builder: &mut BevyBuffersBuilder) {
-        let _ = basic_shapes::fill_circle(
-            self.center,
-            self.radius,
-            self.options,
-            builder
-        );
+    /// The distance from a point to the nearest point along the contour, such as to tell whether a stroke along it was clicked.
+    pub fn distance_to(&self, point: math::Point) -> f32
+    {
+        let count = self.points.len();
+        let segments = if self.is_closed { count } else { count.saturating_sub(1) };
+
+        (0..segments)
+            .map(|i| (self.points[i], self.points[(i + 1) % count]))
+            .map(|(from, to)| {
+                let along = to - from;
+                let t = if along.square_length() > 0.0 { ((point - from).dot(along) / along.square_length()).max(0.0).min(1.0) } else { 0.0 };
+                (from + along * t - point).length()
+            })
+            .fold(self.points.first().map_or(f32::INFINITY, |&first| (first - point).length()), f32::min)
     }
 }
 
-/// Requires the points to represent a convex shape. If the shape is concave the result will likely be incorrect.
-#[derive(Debug, SmartDefault)]
-pub struct FillConvexPolyline<'a, I, G>
+/// Represents a shape whose outline can be flattened into contours without being tessellated.
+///
+/// For stroke shapes, this is the centerline the stroke is built around, and for fill shapes the boundary of the filled area.
+/// Gameplay code can use it to reuse shape definitions for spawn points, paths or colliders, without building a mesh.
+pub trait LyonShapeOutline
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>;
+
+    /// The bounding box of the outline, or `None` if it has no points.
+    ///
+    /// For stroke shapes, this is the bounding box of the centerline, without the width of the stroke.
+    fn bounds(&self, tolerance: f32) -> Option<math::Rect>
+    {
+        let points: Vec<_> = self.outline(tolerance).into_iter().flat_map(|contour| contour.points).collect();
+
+        if points.is_empty() { None } else { Some(math::Rect::from_points(points)) }
+    }
+}
+
+/// Allow outlining shapes by reference, so they can be reused afterwards.
+impl<T> LyonShapeOutline for &T
 where
-    I: IntoIterator<Item=math::Point, IntoIter=G> + Default,
-    G: Iterator<Item=math::Point> + Clone
+    T: LyonShapeOutline + ?Sized
 {
-    pub points: I,
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        (**self).outline(tolerance)
+    }
+}
+
+/// Contours that were already flattened are their own outline, whatever the tolerance.
+impl LyonShapeOutline for [Contour]
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        self.to_vec()
+    }
+}
+
+/// Contours that were already flattened are their own outline, whatever the tolerance.
+impl LyonShapeOutline for Vec<Contour>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        self.as_slice().outline(tolerance)
+    }
+}
+
+/// Internal utility function that flattens every sub-path of a path into a contour.
+fn path_contours(path: &path::Path, tolerance: f32) -> Vec<Contour>
+{
+    let mut contours = vec![];
+    let mut points = vec![];
+
+    for event in path.iter().flattened(tolerance)
+    {
+        match event
+        {
+            path::Event::Begin { at } => points = vec![at],
+            path::Event::Line { to, .. } => points.push(to),
+            path::Event::End { close, .. } => {
+                let mut points = std::mem::take(&mut points);
+
+                // Curves are flattened all the way back to their start, which closing the contour already takes care of
+                if close && points.len() > 1 && (points[0] - points[points.len() - 1]).square_length() <= f32::EPSILON
+                {
+                    points.pop();
+                }
+
+                contours.push(Contour { points, is_closed: close });
+            },
+            _ => {},
+        }
+    }
+
+    contours
+}
+
+/// Internal utility function that winds a closed contour counter-clockwise, so that overlapping contours add up under the non-zero fill rule.
+pub(crate) fn counter_clockwise(points: &[math::Point]) -> Vec<math::Point>
+{
+    match signed_area(points) < 0.0
+    {
+        true => points.iter().rev().copied().collect(),
+        false => points.to_vec(),
+    }
+}
+
+/// Internal utility function that gives twice the area of a closed contour, positive when it winds counter-clockwise.
+pub(crate) fn signed_area(points: &[math::Point]) -> f32
+{
+    points.iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum()
+}
+
+/// Internal utility function that tells whether a point is within a closed polygon, with the even-odd rule.
+pub(crate) fn polygon_contains(polygon: &[math::Point], point: math::Point) -> bool
+{
+    polygon.iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .filter(|&(from, to)| {
+            // Counts the edges crossing a ray going right from the point
+            (from.y > point.y) != (to.y > point.y)
+                && point.x < from.x + (point.y - from.y) / (to.y - from.y) * (to.x - from.x)
+        })
+        .count() % 2 == 1
+}
+
+/// Internal utility function that tells whether a closed contour is a hole, within an odd number of the other closed contours.
+pub(crate) fn is_hole(contours: &[Contour], index: usize) -> bool
+{
+    let point = match contours[index].points.first()
+    {
+        Some(&point) => point,
+        None => return false,
+    };
+
+    contours.iter()
+        .enumerate()
+        .filter(|&(i, other)| i != index && other.is_closed && polygon_contains(&other.points, point))
+        .count() % 2 == 1
+}
+
+/// Internal utility function that smooths a closed contour by cutting its corners a number of times, with Chaikin's algorithm.
+pub(crate) fn smooth_contour(mut points: Vec<math::Point>, iterations: usize) -> Vec<math::Point>
+{
+    for _ in 0..iterations
+    {
+        points = points.iter()
+            .zip(points.iter().cycle().skip(1))
+            .flat_map(|(&from, &to)| vec![from.lerp(to, 0.25), from.lerp(to, 0.75)])
+            .collect();
+    }
+
+    points
+}
+
+/// Internal utility function that gives the outline of the union of any number of polygons,
+/// counter-clockwise around the outside and clockwise around any enclosed gaps.
+///
+/// The polygons are filled together with the non-zero fill rule, and the edges of the triangles that no other triangle shares are the outline.
+pub(crate) fn union_contours<I>(polygons: I) -> Vec<Contour>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[math::Point]>
+{
+    let mut path = path::Path::builder();
+    for polygon in polygons
+    {
+        path.add_polygon(Polygon { points: &counter_clockwise(polygon.as_ref()), closed: true });
+    }
+
+    let mut geometry: tess::VertexBuffers<math::Point, u32> = tess::VertexBuffers::new();
+    let _ = tess::FillTessellator::new().tessellate_path(
+        &path.build(),
+        &tess::FillOptions::DEFAULT.with_fill_rule(tess::FillRule::NonZero),
+        &mut tess::BuffersBuilder::new(&mut geometry, |vertex: tess::FillVertex| vertex.position())
+    );
+
+    // Every edge of every triangle, going counter-clockwise around it
+    let mut edges: HashSet<(u32, u32)> = HashSet::new();
+    for triangle in geometry.indices.chunks_exact(3)
+    {
+        let (a, b, c) = (geometry.vertices[triangle[0] as usize], geometry.vertices[triangle[1] as usize], geometry.vertices[triangle[2] as usize]);
+        let triangle = match (b - a).cross(c - b) < 0.0
+        {
+            true => [triangle[0], triangle[2], triangle[1]],
+            false => [triangle[0], triangle[1], triangle[2]],
+        };
+
+        for i in 0..3
+        {
+            edges.insert((triangle[i], triangle[(i + 1) % 3]));
+        }
+    }
+
+    let mut next: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut starts: Vec<u32> = vec![];
+    for &(from, to) in edges.iter().filter(|&&(from, to)| !edges.contains(&(to, from)))
+    {
+        next.entry(from).or_default().push(to);
+        starts.push(from);
+    }
+
+    // Sorted so the contours don't depend on the order of the set
+    starts.sort_unstable();
+
+    let mut contours = vec![];
+    for start in starts
+    {
+        let mut indices = vec![];
+        let mut at = start;
+
+        while let Some(to) = next.get_mut(&at).and_then(|tos| tos.pop())
+        {
+            indices.push(at);
+            at = to;
+        }
+
+        if indices.len() > 2
+        {
+            contours.push(Contour {
+                points: indices.iter().map(|&index| geometry.vertices[index as usize]).collect(),
+                is_closed: true,
+            });
+        }
+    }
+
+    contours
+}
+
+/// Controls over how a curve is flattened into line segments, on top of the tolerance of the options it is tessellated with.
+///
+/// Curves are split in half until every piece is within the tolerance and turns by less than `max_angle`,
+/// unless that would make more than `max_segments` pieces.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct CurveFlattening
+{
+    /// The most line segments a curve is flattened into, whatever the tolerance.
+    pub max_segments: Option<usize>,
+    /// How much a curve may turn along a single segment, to keep curves that are small on screen from looking faceted once scaled up.
+    pub max_angle: Option<math::Angle>,
+}
+
+impl CurveFlattening
+{
+    /// Flattens a quadratic curve, giving every point along with the subdivision depth it was added at.
+    ///
+    /// The ends of the curve have a depth of `0`, the point splitting it in half `1` and so on.
+    pub fn flatten(&self, curve: &QuadraticBezierSegment<f32>, tolerance: f32) -> Vec<(math::Point, usize)>
+    {
+        // Guards against tolerances so small they could never be reached
+        const MAX_DEPTH: usize = 16;
+
+        let max_segments = self.max_segments.unwrap_or(usize::MAX).max(1);
+
+        // Every segment is kept with the depth of the point it ends at
+        let mut segments = vec![(*curve, 0)];
+
+        for depth in 1..=MAX_DEPTH
+        {
+            let splits = segments.iter().filter(|(segment, _)| self.needs_split(segment, tolerance)).count();
+
+            if splits == 0 || segments.len() + splits > max_segments
+            {
+                break;
+            }
+
+            segments = segments.into_iter()
+                .flat_map(|(segment, end_depth)| {
+                    if self.needs_split(&segment, tolerance)
+                    {
+                        let (first, second) = segment.split(0.5);
+                        vec![(first, depth), (second, end_depth)]
+                    }
+                    else
+                    {
+                        vec![(segment, end_depth)]
+                    }
+                })
+                .collect();
+        }
+
+        std::iter::once((curve.from, 0))
+            .chain(segments.iter().map(|(segment, depth)| (segment.to, *depth)))
+            .collect()
+    }
+
+    /// Internal utility function that checks whether a piece of a curve is too far from its chord or turns too much.
+    fn needs_split(&self, segment: &QuadraticBezierSegment<f32>, tolerance: f32) -> bool
+    {
+        // The furthest a quadratic curve gets from its chord is a quarter of this
+        let deviation = (segment.from.to_vector() - segment.ctrl.to_vector() * 2.0 + segment.to.to_vector()).length() / 4.0;
+
+        let too_sharp = self.max_angle.is_some_and(|max_angle| {
+            (segment.ctrl - segment.from).angle_to(segment.to - segment.ctrl).radians.abs() > max_angle.radians
+        });
+
+        deviation > tolerance || too_sharp
+    }
+}
+
+/// Internal utility function that flattens a circle into its contour.
+fn circle_contours(center: math::Point, radius: f32, tolerance: f32) -> Vec<Contour>
+{
+    let mut builder = path::Path::builder();
+    builder.add_circle(center, radius, Winding::Positive);
+
+    path_contours(&builder.build(), tolerance)
+}
+
+/// Internal utility function that flattens a circular arc into its points, from its start to its end.
+pub(crate) fn arc_points(center: math::Point, radius: f32, start_angle: math::Angle, sweep_angle: math::Angle, tolerance: f32) -> Vec<math::Point>
+{
+    let arc = Arc {
+        center,
+        radii: math::vector(radius, radius),
+        start_angle,
+        sweep_angle,
+        x_rotation: math::Angle::zero(),
+    };
+
+    std::iter::once(arc.from())
+        .chain(arc.flattened(tolerance))
+        .collect()
+}
+
+/// Internal utility function that gives the corners of a regular polygon, counter-clockwise from the one at `rotation`.
+fn regular_polygon_points(center: math::Point, radius: f32, sides: usize, rotation: math::Angle) -> Vec<math::Point>
+{
+    (0..sides)
+        .map(|i| {
+            let angle = rotation.radians + std::f32::consts::PI * 2.0 * i as f32 / sides as f32;
+            center + math::vector(angle.cos(), angle.sin()) * radius
+        })
+        .collect()
+}
+
+/// Internal utility function that gives the corners of a star, alternating between its outer and inner points.
+fn star_points(center: math::Point, points: usize, inner_radius: f32, outer_radius: f32, rotation: math::Angle) -> Vec<math::Point>
+{
+    (0..points * 2)
+        .map(|i| {
+            let angle = rotation.radians + std::f32::consts::PI * i as f32 / points as f32;
+            let radius = if i % 2 == 0 { outer_radius } else { inner_radius };
+            center + math::vector(angle.cos(), angle.sin()) * radius
+        })
+        .collect()
+}
+
+/// Internal utility function that gives the points along a superellipse, counter-clockwise from its right.
+fn superellipse_points(center: math::Point, radii: math::Vector, exponent: f32, tolerance: f32) -> Vec<math::Point>
+{
+    // As many points as a circle of the larger radius needs, twice over for the tighter curves at the corners of squarer shapes
+    let step = (8.0 * tolerance / radii.x.max(radii.y)).sqrt();
+    let count = ((std::f32::consts::PI * 4.0 / step).ceil() as usize).max(16).min(4096);
+    let power = 2.0 / exponent.max(f32::EPSILON);
+
+    (0..count)
+        .map(|i| {
+            let (sin, cos) = (std::f32::consts::PI * 2.0 * i as f32 / count as f32).sin_cos();
+            center + math::vector(
+                radii.x * cos.signum() * cos.abs().powf(power),
+                radii.y * sin.signum() * sin.abs().powf(power),
+            )
+        })
+        .collect()
+}
+
+/// Internal utility function that gives the contour of the corners of a rect.
+fn rect_contours(rect: &math::Rect) -> Vec<Contour>
+{
+    let (min, max) = (rect.min(), rect.max());
+
+    vec![Contour {
+        points: vec![min, math::point(max.x, min.y), max, math::point(min.x, max.y)],
+        is_closed: true,
+    }]
+}
+
+/// Internal utility function that gives the corners of a rect of the given size, rotated around its center.
+fn rotated_rect_corners(center: math::Point, size: math::Size, angle: math::Angle) -> [math::Point; 4]
+{
+    let rotation = math::Rotation::new(angle);
+    let (x, y) = (size.width / 2.0, size.height / 2.0);
+
+    [
+        center + rotation.transform_vector(math::vector(-x, -y)),
+        center + rotation.transform_vector(math::vector(x, -y)),
+        center + rotation.transform_vector(math::vector(x, y)),
+        center + rotation.transform_vector(math::vector(-x, y)),
+    ]
+}
+
+/// Which corners of a rect are rounded, an easier way of building [`BorderRadii`] when the rounded corners all share a radius.
+///
+/// ```rust
+/// # use bevy_lyon::{math, shapes};
+/// // A tab, with only its top corners rounded
+/// let tab = shapes::FillRoundedRect {
+///     rect: math::Rect::new(math::point(0.0, 0.0), math::size(80.0, 30.0)),
+///     radii: shapes::Corners::TOP.radii(8.0),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// Corners are named the way lyon names them, with the y axis pointing down, so the `top` corners are the ones at the rect's minimum y.
+/// With bevy's y axis pointing up, they are drawn at the bottom of the rect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Corners
+{
+    pub top_left: bool,
+    pub top_right: bool,
+    pub bottom_left: bool,
+    pub bottom_right: bool,
+}
+
+impl Corners
+{
+    pub const NONE: Corners = Corners { top_left: false, top_right: false, bottom_left: false, bottom_right: false };
+    pub const ALL: Corners = Corners { top_left: true, top_right: true, bottom_left: true, bottom_right: true };
+    pub const TOP: Corners = Corners { top_left: true, top_right: true, bottom_left: false, bottom_right: false };
+    pub const BOTTOM: Corners = Corners { top_left: false, top_right: false, bottom_left: true, bottom_right: true };
+    pub const LEFT: Corners = Corners { top_left: true, top_right: false, bottom_left: true, bottom_right: false };
+    pub const RIGHT: Corners = Corners { top_left: false, top_right: true, bottom_left: false, bottom_right: true };
+
+    /// The radii rounding the enabled corners by `radius`, leaving the others square.
+    pub fn radii(self, radius: f32) -> BorderRadii
+    {
+        let radius_if = |enabled: bool| if enabled { radius } else { 0.0 };
+
+        BorderRadii {
+            top_left: radius_if(self.top_left),
+            top_right: radius_if(self.top_right),
+            bottom_left: radius_if(self.bottom_left),
+            bottom_right: radius_if(self.bottom_right),
+        }
+    }
+}
+
+/// Internal utility function that builds the path of a polyline with its corners rounded, corners past the end of `radii` using `radius`.
+///
+/// The radius of each corner is limited so that it doesn't take up more than half of either of the edges next to it.
+/// Open polylines keep the sharp points at either of their ends.
+fn rounded_polygon_path(points: &[math::Point], radius: f32, radii: &[f32], is_closed: bool) -> path::Path
+{
+    let mut builder = path::Path::builder().with_svg();
+
+    if points.len() < 3
+    {
+        for (i, &point) in points.iter().enumerate()
+        {
+            if i == 0 { builder.move_to(point); } else { builder.line_to(point); }
+        }
+        return builder.build();
+    }
+
+    let count = points.len();
+    let corners = if is_closed { 0..count } else { 1..count - 1 };
+
+    if !is_closed
+    {
+        builder.move_to(points[0]);
+    }
+
+    for i in corners
+    {
+        let (previous, corner, next) = (points[(i + count - 1) % count], points[i], points[(i + 1) % count]);
+        let (to_previous, to_next) = (previous - corner, next - corner);
+        let radius = radii.get(i).copied().unwrap_or(radius);
+
+        // Half of the angle between the edges, which places where the arc touches them
+        let half_angle = to_previous.angle_to(to_next).radians.abs() / 2.0;
+        let distance = (radius / half_angle.tan())
+            .min(to_previous.length() / 2.0)
+            .min(to_next.length() / 2.0);
+
+        let start = corner + to_previous.normalize() * distance;
+        let end = corner + to_next.normalize() * distance;
+        let is_first = is_closed && i == 0;
+
+        if radius <= 0.0 || !distance.is_finite() || distance <= 0.0 || half_angle.tan() <= f32::EPSILON
+        {
+            if is_first { builder.move_to(corner); } else { builder.line_to(corner); }
+            continue;
+        }
+
+        if is_first { builder.move_to(start); } else { builder.line_to(start); }
+
+        builder.arc_to(
+            math::vector(distance * half_angle.tan(), distance * half_angle.tan()),
+            math::Angle::zero(),
+            ArcFlags { large_arc: false, sweep: to_next.cross(to_previous) > 0.0 },
+            end
+        );
+    }
+
+    if is_closed
+    {
+        builder.close();
+    }
+    else
+    {
+        builder.line_to(points[count - 1]);
+    }
+
+    builder.build()
+}
+
+/// Internal utility function that moves every edge of a closed contour outwards by `distance`, or inwards when it is negative,
+/// joining the edges back together at the corners they move away from. The offset contour keeps the winding of the original one.
+fn offset_contour(points: &[math::Point], distance: f32, join: OffsetJoin, tolerance: f32) -> Vec<math::Point>
+{
+    // Consecutive duplicates have no direction to move along
+    let mut points = points.to_vec();
+    points.dedup();
+    if points.len() > 1 && points[0] == points[points.len() - 1]
+    {
+        points.pop();
+    }
+
+    let count = points.len();
+    if count < 3 || distance == 0.0
+    {
+        return points;
+    }
+
+    // The offset is made counter-clockwise, with the outside on the right of the edges, then wound back the way it was
+    let clockwise = signed_area(&points) < 0.0;
+    if clockwise
+    {
+        points.reverse();
+    }
+
+    let normal = |from: math::Point, to: math::Point| {
+        let direction = (to - from).normalize();
+        math::vector(direction.y, -direction.x)
+    };
+
+    let mut offset = vec![];
+
+    for i in 0..count
+    {
+        let (previous, corner, next) = (points[(i + count - 1) % count], points[i], points[(i + 1) % count]);
+        let (before, after) = (normal(previous, corner), normal(corner, next));
+        let turn = (corner - previous).cross(next - corner);
+
+        // Edges going straight on meet where they were, and edges doubling back have no crossing to meet at
+        if (before - after).square_length() <= f32::EPSILON
+        {
+            offset.push(corner + before * distance);
+            continue;
+        }
+        if (before + after).square_length() <= f32::EPSILON
+        {
+            offset.push(corner + before * distance);
+            offset.push(corner + after * distance);
+            continue;
+        }
+
+        // The edges meet again where their offset lines cross, unless they move away from each other at the corner
+        let bisector = (before + after).normalize();
+        let miter = bisector * (distance / bisector.dot(before));
+        let (before, after) = (before * distance, after * distance);
+
+        if turn * distance <= 0.0
+        {
+            offset.push(corner + miter);
+            continue;
+        }
+
+        match join
+        {
+            OffsetJoin::Miter { limit } if miter.length() <= limit * distance.abs() => offset.push(corner + miter),
+            OffsetJoin::Round => offset.extend(arc_points(
+                corner,
+                distance.abs(),
+                before.angle_from_x_axis(),
+                before.angle_to(after),
+                tolerance,
+            )),
+            _ =>
+            {
+                offset.push(corner + before);
+                offset.push(corner + after);
+            }
+        }
+    }
+
+    if clockwise
+    {
+        offset.reverse();
+    }
+
+    offset
+}
+
+/// Internal utility function that builds the path of a Catmull-Rom spline through points, out of a cubic curve between each of them.
+///
+/// The tangent at every point is along the line between its neighbours, scaled by `tension`, `0.5` being the usual Catmull-Rom spline
+/// and `0.0` straight lines. The ends of open splines are their own neighbours.
+fn spline_path(points: &[math::Point], tension: f32, is_closed: bool) -> path::Path
+{
+    let mut builder = path::Path::builder();
+    let count = points.len();
+
+    if count == 0
+    {
+        return builder.build();
+    }
+
+    let point = |i: isize| {
+        if is_closed { points[i.rem_euclid(count as isize) as usize] } else { points[i.max(0).min(count as isize - 1) as usize] }
+    };
+    let spans = if is_closed { count } else { count - 1 };
+
+    builder.begin(points[0]);
+
+    for i in 0..spans as isize
+    {
+        let (previous, from, to, next) = (point(i - 1), point(i), point(i + 1), point(i + 2));
+
+        builder.cubic_bezier_to(
+            from + (to - previous) * tension / 3.0,
+            to - (next - from) * tension / 3.0,
+            to
+        );
+    }
+
+    builder.end(is_closed);
+    builder.build()
+}
+
+/// Internal utility function that builds the path of a circle with a bite taken out of it by another circle.
+fn crescent_path(center: math::Point, radius: f32, cut_center: math::Point, cut_radius: f32) -> path::Path
+{
+    let offset = cut_center - center;
+    let distance = offset.length();
+
+    let mut builder = path::Path::builder();
+
+    if distance >= radius + cut_radius
+    {
+        // The cut misses the circle entirely
+        builder.add_circle(center, radius, Winding::Positive);
+        return builder.build();
+    }
+    if distance + radius <= cut_radius
+    {
+        // The cut covers the whole circle
+        return builder.build();
+    }
+    if distance + cut_radius <= radius
+    {
+        // The cut is a hole within the circle
+        builder.add_circle(center, radius, Winding::Positive);
+        builder.add_circle(cut_center, cut_radius, Winding::Negative);
+        return builder.build();
+    }
+
+    // The circles cross at two points, the outline goes around the circle away from the cut and back along the cut
+    let along = (radius * radius - cut_radius * cut_radius + distance * distance) / (2.0 * distance);
+    let half_angle = math::Angle::radians((along / radius).max(-1.0).min(1.0).acos());
+    let offset_angle = math::Angle::radians(offset.y.atan2(offset.x));
+    let start = center + math::Vector::from_angle_and_length(offset_angle + half_angle, radius);
+    let end = center + math::Vector::from_angle_and_length(offset_angle - half_angle, radius);
+
+    let cut_start = end - cut_center;
+    let cut_end = start - cut_center;
+    let cut_sweep = (cut_start.y.atan2(cut_start.x) - cut_end.y.atan2(cut_end.x)).rem_euclid(std::f32::consts::PI * 2.0);
+
+    let mut builder = builder.with_svg();
+    builder.move_to(start);
+    builder.arc(center, math::vector(radius, radius), math::Angle::two_pi() - half_angle * 2.0, math::Angle::zero());
+    builder.arc(cut_center, math::vector(cut_radius, cut_radius), math::Angle::radians(-cut_sweep), math::Angle::zero());
+    builder.close();
+    builder.build()
+}
+
+/// Internal utility function that builds the path of a heart, out of a cubic curve for each of its halves.
+fn heart_path(center: math::Point, size: f32) -> path::Path
+{
+    // Laid out in a square from -1 to 1, which is then scaled to the size of the heart
+    let point = |x: f32, y: f32| center + math::vector(x, y) * (size / 2.0);
+    let (tip, dip) = (point(0.0, -1.0), point(0.0, 0.5));
+
+    let mut builder = path::Path::builder();
+    builder.begin(tip);
+    builder.cubic_bezier_to(point(-1.9, 0.1), point(-0.6, 1.2), dip);
+    builder.cubic_bezier_to(point(0.6, 1.2), point(1.9, 0.1), tip);
+    builder.end(true);
+    builder.build()
+}
+
+/// Internal utility function that builds the path of a rect with rounded corners.
+fn rounded_rect_path(rect: &math::Rect, radii: &BorderRadii) -> path::Path
+{
+    let mut builder = path::Path::builder();
+    builder.add_rounded_rectangle(&rect.to_box2d(), radii, Winding::Positive);
+    builder.build()
+}
+
+/// A filled arrow from `from` to `to`, with its head at `to`, or at both ends if it is double headed.
+///
+/// The head is shrunk to fit when the arrow is too short for it.
+#[derive(Debug, SmartDefault)]
+pub struct Arrow<'a> {
+    pub from: math::Point,
+    #[default(math::point(50.0, 0.0))]
+    pub to: math::Point,
+    #[default = 4.0]
+    pub shaft_width: f32,
+    #[default = 12.0]
+    pub head_length: f32,
+    #[default = 12.0]
+    pub head_width: f32,
+    pub double_headed: bool,
     #[default(&tess::FillOptions::DEFAULT)]
     pub options: &'a tess::FillOptions,
 }
 
-impl<I, G> LyonShapeBuilder for FillConvexPolyline<'_, I, G>
-where
-    I: IntoIterator<Item=math::Point, IntoIter=G> + Default,
-    G: Iterator<Item=math::Point> + Clone
+impl Arrow<'_>
+{
+    /// The corners of the arrow, counter-clockwise from the tip at `to`.
+    pub fn corners(&self) -> Vec<math::Point>
+    {
+        let direction = (self.to - self.from).try_normalize().unwrap_or(math::vector(1.0, 0.0));
+        let side = math::vector(-direction.y, direction.x);
+        let length = (self.to - self.from).length();
+
+        let heads = if self.double_headed { 2.0 } else { 1.0 };
+        let head_length = self.head_length.min(length / heads);
+        let (shaft, head) = (side * (self.shaft_width / 2.0), side * (self.head_width / 2.0));
+
+        // Where the shaft meets each head, which is the end itself when there is no head there
+        let to_base = self.to - direction * head_length;
+        let from_base = if self.double_headed { self.from + direction * head_length } else { self.from };
+
+        let mut corners = vec![self.to, to_base + head, to_base + shaft];
+
+        if self.double_headed
+        {
+            corners.extend_from_slice(&[from_base + shaft, from_base + head, self.from, from_base - head, from_base - shaft]);
+        }
+        else
+        {
+            corners.extend_from_slice(&[from_base + shaft, from_base - shaft]);
+        }
+
+        corners.extend_from_slice(&[to_base - shaft, to_base - head]);
+        corners
+    }
+}
+
+impl LyonShapeBuilder for Arrow<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.corners(), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for Arrow<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.corners(), is_closed: true }]
+    }
+}
+
+/// A label's background with a leader line pointing from it to `target`, such as an annotation on a diagram or a map.
+///
+/// The leader leaves the middle of the label's side facing the target, or its elbow if it has one, and ends in an arrowhead at the target.
+/// Every part is filled with the same options, so the whole callout is a single shape.
+#[derive(Debug, SmartDefault)]
+pub struct Callout<'a> {
+    pub target: math::Point,
+    /// Where the leader turns on its way from the label to the target, if it does.
+    pub elbow: Option<math::Point>,
+    #[default(math::Rect::new(math::point(40.0, 30.0), math::size(60.0, 20.0)))]
+    pub label: math::Rect,
+    #[default = 4.0]
+    pub corner_radius: f32,
+    #[default = 2.0]
+    pub line_width: f32,
+    #[default = 8.0]
+    pub head_length: f32,
+    #[default = 8.0]
+    pub head_width: f32,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl Callout<'_>
+{
+    /// The points of the leader line, from where it leaves the label to the target.
+    pub fn leader(&self) -> Vec<math::Point>
+    {
+        let towards = self.elbow.unwrap_or(self.target);
+        let center = self.label.center();
+        let x = if towards.x < center.x { self.label.min_x() } else { self.label.max_x() };
+
+        std::iter::once(math::point(x, center.y))
+            .chain(self.elbow)
+            .chain(std::iter::once(self.target))
+            .collect()
+    }
+
+    /// Internal utility function that gives the arrow along the last segment of the leader.
+    fn arrow(&self, leader: &[math::Point]) -> Arrow<'_>
+    {
+        Arrow {
+            from: leader[leader.len() - 2],
+            to: self.target,
+            shaft_width: self.line_width,
+            head_length: self.head_length,
+            head_width: self.head_width,
+            double_headed: false,
+            options: self.options,
+        }
+    }
+
+    /// Internal utility function that gives the corner radii of the label, shrunk to fit within it.
+    fn label_radii(&self) -> BorderRadii
+    {
+        BorderRadii::new(self.corner_radius.min(self.label.width() / 2.0).min(self.label.height() / 2.0))
+    }
+}
+
+impl LyonShapeBuilder for Callout<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let leader = self.leader();
+        tess::FillTessellator::new().tessellate_path(
+            &rounded_rect_path(&self.label, &self.label_radii()),
+            self.options,
+            builder
+        )?;
+
+        // The segment before the elbow has round caps, which fill in the corner where it meets the arrow
+        if leader.len() > 2
+        {
+            let options = tess::StrokeOptions::tolerance(self.options.tolerance)
+                .with_line_width(self.line_width)
+                .with_line_cap(tess::LineCap::Round);
+
+            tess::StrokeTessellator::new().tessellate_polygon(
+                Polygon { points: &leader[..2], closed: false },
+                &options,
+                builder
+            )?;
+        }
+
+        self.arrow(&leader).try_build(builder)
+    }
+}
+
+impl LyonShapeOutline for Callout<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        let leader = self.leader();
+        let mut contours = path_contours(&rounded_rect_path(&self.label, &self.label_radii()), tolerance);
+        contours.push(Contour { points: self.arrow(&leader).corners(), is_closed: true });
+
+        if leader.len() > 2
+        {
+            contours.push(Contour { points: leader[..2].to_vec(), is_closed: false });
+        }
+
+        contours
+    }
+}
+
+/// The area between a circular arc and the chord joining its ends, see [`FillSector`] for a pie slice.
+#[derive(Debug, SmartDefault)]
+pub struct FillArc<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub radius: f32,
+    pub start_angle: math::Angle,
+    #[default(math::Angle::degrees(90.0))]
+    pub sweep_angle: math::Angle,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl LyonShapeBuilder for FillArc<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.outline(self.options.tolerance)[0].points, closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillArc<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour {
+            points: arc_points(self.center, self.radius, self.start_angle, self.sweep_angle, tolerance),
+            is_closed: true,
+        }]
+    }
+}
+
+/// The area between a chord and the arc of a circle it cuts off, counter-clockwise from `start_angle` to `end_angle`, see [`FillArc`] for one given by its sweep.
+///
+/// Made from a fill level through [`FillChordSegment::level`], it is the liquid in a round flask or the filled part of a circular gauge.
+#[derive(Debug, SmartDefault)]
+pub struct FillChordSegment<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub radius: f32,
+    #[default(math::Angle::degrees(180.0))]
+    pub start_angle: math::Angle,
+    #[default(math::Angle::degrees(360.0))]
+    pub end_angle: math::Angle,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl<'a> FillChordSegment<'a>
+{
+    /// The part of a circle below a level chord, from `0.0` for empty at its bottom to `1.0` for the whole circle.
+    pub fn level(center: math::Point, radius: f32, level: f32, options: &'a tess::FillOptions) -> Self
+    {
+        let half_chord = (level.max(0.0).min(1.0) * 2.0 - 1.0).asin();
+
+        FillChordSegment {
+            center,
+            radius,
+            start_angle: math::Angle::radians(std::f32::consts::PI - half_chord),
+            end_angle: math::Angle::radians(std::f32::consts::PI * 2.0 + half_chord),
+            options,
+        }
+    }
+
+    /// The angle from the start of the arc to its end.
+    pub fn sweep_angle(&self) -> math::Angle
+    {
+        self.end_angle - self.start_angle
+    }
+}
+
+impl LyonShapeBuilder for FillChordSegment<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.outline(self.options.tolerance)[0].points, closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillChordSegment<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour {
+            points: arc_points(self.center, self.radius, self.start_angle, self.sweep_angle(), tolerance),
+            is_closed: true,
+        }]
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct FillCircle<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub radius: f32,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions
+}
+
+impl LyonShapeBuilder for FillCircle<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_circle(
+            self.center,
+            self.radius,
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillCircle<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        circle_contours(self.center, self.radius, tolerance)
+    }
+}
+
+/// A circle with a bite taken out of it by a second circle, `cut_offset` away from its center.
+#[derive(Debug, SmartDefault)]
+pub struct FillCrescent<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub radius: f32,
+    #[default(math::vector(10.0, 0.0))]
+    pub cut_offset: math::Vector,
+    #[default = 22.0]
+    pub cut_radius: f32,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl LyonShapeBuilder for FillCrescent<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_path(
+            &crescent_path(self.center, self.radius, self.center + self.cut_offset, self.cut_radius),
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillCrescent<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(&crescent_path(self.center, self.radius, self.center + self.cut_offset, self.cut_radius), tolerance)
+    }
+}
+
+/// A diamond with its corners at the middle of the sides of a `width` by `height` rect around `center`, such as for map markers.
+#[derive(Debug, SmartDefault)]
+pub struct FillDiamond<'a> {
+    pub center: math::Point,
+    #[default = 20.0]
+    pub width: f32,
+    #[default = 25.0]
+    pub height: f32,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl FillDiamond<'_>
+{
+    /// The corners of the diamond, counter-clockwise from its right.
+    pub fn corners(&self) -> [math::Point; 4]
+    {
+        let (x, y) = (math::vector(self.width / 2.0, 0.0), math::vector(0.0, self.height / 2.0));
+
+        [self.center + x, self.center + y, self.center - x, self.center - y]
+    }
+}
+
+impl LyonShapeBuilder for FillDiamond<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.corners(), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillDiamond<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.corners().to_vec(), is_closed: true }]
+    }
+}
+
+/// A heart pointing down with bevy's y axis pointing up, `size` wide and with its tip `size / 2` below `center`.
+#[derive(Debug, SmartDefault)]
+pub struct FillHeart<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub size: f32,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl LyonShapeBuilder for FillHeart<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_path(
+            &heart_path(self.center, self.size),
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillHeart<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(&heart_path(self.center, self.size), tolerance)
+    }
+}
+
+/// Requires the points to represent a convex shape. If the shape is concave the result will likely be incorrect.
+#[derive(Debug, SmartDefault)]
+pub struct FillConvexPolyline<'a, I, G>
+where
+    I: IntoIterator<Item=math::Point, IntoIter=G> + Default,
+    G: Iterator<Item=math::Point> + Clone
+{
+    pub points: I,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl<I, G> LyonShapeBuilder for FillConvexPolyline<'_, I, G>
+where
+    I: IntoIterator<Item=math::Point, IntoIter=G> + Default,
+    G: Iterator<Item=math::Point> + Clone
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let points: Vec<_> = self.points.into_iter().collect();
+
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &points, closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl<I, G> LyonShapeOutline for FillConvexPolyline<'_, I, G>
+where
+    I: IntoIterator<Item=math::Point, IntoIter=G> + Default + Clone,
+    G: Iterator<Item=math::Point> + Clone
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points.clone().into_iter().collect(), is_closed: true }]
+    }
+}
+
+/// A parallelogram with a corner at `origin` and sides along `base` and `side`, such as for a skewed banner.
+#[derive(Debug, SmartDefault)]
+pub struct FillParallelogram<'a> {
+    pub origin: math::Point,
+    #[default(math::vector(25.0, 0.0))]
+    pub base: math::Vector,
+    #[default(math::vector(10.0, 25.0))]
+    pub side: math::Vector,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl FillParallelogram<'_>
+{
+    /// The corners of the parallelogram, starting from `origin` and going along `base` first.
+    pub fn corners(&self) -> [math::Point; 4]
+    {
+        [self.origin, self.origin + self.base, self.origin + self.base + self.side, self.origin + self.side]
+    }
+}
+
+impl LyonShapeBuilder for FillParallelogram<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.corners(), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillParallelogram<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.corners().to_vec(), is_closed: true }]
+    }
+}
+
+/// Any path lyon can build, owned or borrowed, filled with the full fill tessellator.
+///
+/// Paths have no meaningful default, so this is made with [`FillPath::new`] or [`FillPath::with_fill_rule`], or by giving all of its fields.
+#[derive(Debug)]
+pub struct FillPath<'a, P>
+where
+    P: Borrow<path::Path>
+{
+    pub path: P,
+    pub options: &'a tess::FillOptions,
+}
+
+impl<P> FillPath<'_, P>
+where
+    P: Borrow<path::Path>
+{
+    /// Fills a path with the default options.
+    pub fn new(path: P) -> Self
+    {
+        FillPath { path, options: &tess::FillOptions::DEFAULT }
+    }
+
+    /// Fills a path with the default options but for the fill rule, which decides what is inside of a path crossing itself.
+    pub fn with_fill_rule(path: P, fill_rule: tess::FillRule) -> Self
+    {
+        const EVEN_ODD: tess::FillOptions = tess::FillOptions::DEFAULT.with_fill_rule(tess::FillRule::EvenOdd);
+        const NON_ZERO: tess::FillOptions = tess::FillOptions::DEFAULT.with_fill_rule(tess::FillRule::NonZero);
+
+        let options = match fill_rule
+        {
+            tess::FillRule::EvenOdd => &EVEN_ODD,
+            tess::FillRule::NonZero => &NON_ZERO,
+        };
+
+        FillPath { path, options }
+    }
+}
+
+impl<P> LyonShapeBuilder for FillPath<'_, P>
+where
+    P: Borrow<path::Path>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_path(self.path.borrow(), self.options, builder)
+    }
+}
+
+impl<P> LyonShapeOutline for FillPath<'_, P>
+where
+    P: Borrow<path::Path>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(self.path.borrow(), tolerance)
+    }
+}
+
+// Debug is not derived because tess::FillTessellator does not derive it.
+// We can hack around now having Default, but hacking around not having Debug is a bit much...
+#[derive(SmartDefault)]
+pub struct FillPolyline<'a, I>
+where
+    I: IntoIterator<Item=math::Point> + Default
+{
+    pub points: I,
+    #[default(tess::FillTessellator::new())]
+    pub tessellator: tess::FillTessellator,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl<I> LyonShapeBuilder for FillPolyline<'_, I>
+where
+    I: IntoIterator<Item=math::Point> + Default
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(mut self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let points: Vec<_> = self.points.into_iter().collect();
+
+        self.tessellator.tessellate_polygon(
+            Polygon { points: &points, closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl<I> LyonShapeOutline for FillPolyline<'_, I>
+where
+    I: IntoIterator<Item=math::Point> + Default + Clone
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points.clone().into_iter().collect(), is_closed: true }]
+    }
+}
+
+/// A polygon with its corners rounded, by `radius` or by the radius of each corner in `radii`, such as for the outlines of modern UI elements.
+///
+/// Corners are only rounded as much as half of the edges next to them allow.
+#[derive(Debug, SmartDefault)]
+pub struct FillRoundedPolygon<'a, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    pub points: I,
+    #[default = 5.0]
+    pub radius: f32,
+    /// The radius of each corner in the order of the points, with corners past its end using `radius`.
+    pub radii: Vec<f32>,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl<I> LyonShapeBuilder for FillRoundedPolygon<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_path(
+            &rounded_polygon_path(self.points.as_ref(), self.radius, &self.radii, true),
+            self.options,
+            builder
+        )
+    }
+}
+
+impl<I> LyonShapeOutline for FillRoundedPolygon<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(&rounded_polygon_path(self.points.as_ref(), self.radius, &self.radii, true), tolerance)
+    }
+}
+
+// TODO: Check what happens when the points aren't at right angles!
+#[derive(Debug, SmartDefault)]
+pub struct FillQuad<'a> {
+    #[default([ math::point(0.0, 0.0), math::point(0.0, 25.0), math::point(25.0, 25.0), math::point(25.0, 0.0) ])]
+    pub points: [math::Point; 4],
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl LyonShapeBuilder for FillQuad<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.points, closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillQuad<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points.to_vec(), is_closed: true }]
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct FillRect<'a> {
+    pub rect: math::Rect,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl LyonShapeBuilder for FillRect<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_rectangle(
+            &self.rect.to_box2d(),
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillRect<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        rect_contours(&self.rect)
+    }
+}
+
+/// A polygon with `sides` equal sides, such as a hexagon, whose corners all lie on a circle of `radius`.
+#[derive(Debug, SmartDefault)]
+pub struct FillRegularPolygon<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub radius: f32,
+    #[default = 6]
+    pub sides: usize,
+    /// The angle of the first corner, the others following counter-clockwise.
+    pub rotation: math::Angle,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl FillRegularPolygon<'_>
+{
+    /// The corners of the polygon, counter-clockwise from the one at `rotation`.
+    pub fn points(&self) -> Vec<math::Point>
+    {
+        regular_polygon_points(self.center, self.radius, self.sides, self.rotation)
+    }
+}
+
+impl LyonShapeBuilder for FillRegularPolygon<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.points(), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillRegularPolygon<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points(), is_closed: true }]
+    }
+}
+
+/// A rect turned by `angle` around its center, such as an oriented bounding box.
+#[derive(Debug, SmartDefault)]
+pub struct FillRotatedRect<'a> {
+    pub center: math::Point,
+    #[default(math::size(25.0, 25.0))]
+    pub size: math::Size,
+    pub angle: math::Angle,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl FillRotatedRect<'_>
+{
+    /// The corners of the rect, counter-clockwise from the one that was at its minimum before the rotation.
+    pub fn corners(&self) -> [math::Point; 4]
+    {
+        rotated_rect_corners(self.center, self.size, self.angle)
+    }
+}
+
+impl LyonShapeBuilder for FillRotatedRect<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.corners(), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillRotatedRect<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.corners().to_vec(), is_closed: true }]
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct FillRoundedRect<'a> {
+    pub rect: math::Rect,
+    #[default(BorderRadii::new(10.0))]
+    pub radii: BorderRadii,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl LyonShapeBuilder for FillRoundedRect<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_path(
+            &rounded_rect_path(&self.rect, &self.radii),
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillRoundedRect<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(&rounded_rect_path(&self.rect, &self.radii), tolerance)
+    }
+}
+
+/// A pie slice, the area between a circular arc and the center of its circle, such as a slice of a pie chart or of a radial menu.
+#[derive(Debug, SmartDefault)]
+pub struct FillSector<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub radius: f32,
+    pub start_angle: math::Angle,
+    #[default(math::Angle::degrees(90.0))]
+    pub sweep_angle: math::Angle,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl LyonShapeBuilder for FillSector<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.outline(self.options.tolerance)[0].points, closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillSector<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        let mut points = vec![self.center];
+        points.extend(arc_points(self.center, self.radius, self.start_angle, self.sweep_angle, tolerance));
+
+        vec![Contour { points, is_closed: true }]
+    }
+}
+
+/// A closed Catmull-Rom spline going smoothly through every one of its points, such as for the outline of a track.
+#[derive(Debug, SmartDefault)]
+pub struct FillSpline<'a, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    pub points: I,
+    /// How far the curve bulges out between the points, `0.5` being the usual Catmull-Rom spline and `0.0` a polygon.
+    #[default = 0.5]
+    pub tension: f32,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl<I> LyonShapeBuilder for FillSpline<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_path(
+            &spline_path(self.points.as_ref(), self.tension, true),
+            self.options,
+            builder
+        )
+    }
+}
+
+impl<I> LyonShapeOutline for FillSpline<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(&spline_path(self.points.as_ref(), self.tension, true), tolerance)
+    }
+}
+
+/// A star with `points` branches, whose outer points lie on a circle of `outer_radius` and inner corners on one of `inner_radius`.
+#[derive(Debug, SmartDefault)]
+pub struct FillStar<'a> {
+    pub center: math::Point,
+    #[default = 5]
+    pub points: usize,
+    #[default = 10.0]
+    pub inner_radius: f32,
+    #[default = 25.0]
+    pub outer_radius: f32,
+    /// The angle of the first outer point, the others following counter-clockwise.
+    pub rotation: math::Angle,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl FillStar<'_>
+{
+    /// The corners of the star, counter-clockwise from the outer point at `rotation`, alternating between outer points and inner corners.
+    pub fn corners(&self) -> Vec<math::Point>
+    {
+        star_points(self.center, self.points, self.inner_radius, self.outer_radius, self.rotation)
+    }
+}
+
+impl LyonShapeBuilder for FillStar<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.corners(), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillStar<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.corners(), is_closed: true }]
+    }
+}
+
+/// The fill of a shape's outline with the outline of another shape cut out of it, see [`LyonMeshBuilder::with_subtracted`].
+///
+/// [`LyonMeshBuilder::with_subtracted`]: crate::mesh_builder::LyonMeshBuilder::with_subtracted
+#[derive(Debug)]
+pub struct FillSubtracted<'a, B, C>
+where
+    B: LyonShapeOutline,
+    C: LyonShapeOutline
+{
+    pub base: B,
+    pub cutter: C,
+    /// The fill rule is ignored, the hole is made with [`EvenOdd`](tess::FillRule::EvenOdd).
+    pub options: &'a tess::FillOptions,
+}
+
+impl<B, C> LyonShapeBuilder for FillSubtracted<'_, B, C>
+where
+    B: LyonShapeOutline,
+    C: LyonShapeOutline
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let mut path = path::Path::builder();
+        for contour in self.outline(self.options.tolerance)
+        {
+            if contour.points.len() > 2
+            {
+                path.add_polygon(Polygon { points: &contour.points, closed: true });
+            }
+        }
+
+        let options = self.options.with_fill_rule(tess::FillRule::EvenOdd);
+        tess::FillTessellator::new().tessellate_path(&path.build(), &options, builder)
+    }
+}
+
+impl<B, C> LyonShapeOutline for FillSubtracted<'_, B, C>
+where
+    B: LyonShapeOutline,
+    C: LyonShapeOutline
+{
+    /// The contours of the base then the ones of the cutter, which are clipped to the base when it is a single convex contour.
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        let base = self.base.outline(tolerance);
+        let mut cutter = self.cutter.outline(tolerance);
+
+        if let [contour] = base.as_slice()
+        {
+            if is_convex(&contour.points)
+            {
+                for cut in &mut cutter
+                {
+                    cut.points = clip_to_convex(&cut.points, &contour.points);
+                    cut.is_closed = true;
+                }
+            }
+        }
+
+        base.into_iter().chain(cutter).collect()
+    }
+}
+
+/// How the outer ends of the wedges of a [`FillSunburst`] are shaped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmartDefault)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum SunburstKind
+{
+    /// Wedges whose ends follow the circles of the sunburst, like the slices of a ring.
+    #[default]
+    Wedges,
+    /// Rays whose ends are straight, like the beams behind a pickup.
+    Rays,
+}
+
+/// Wedges spread evenly around a center between `inner_radius` and `outer_radius`, with `gap_angle` between neighbouring ones.
+///
+/// The first wedge starts at `rotation`, the others following counter-clockwise. All of them are filled as a single shape.
+#[derive(Debug, SmartDefault)]
+pub struct FillSunburst<'a> {
+    pub center: math::Point,
+    #[default = 12]
+    pub count: usize,
+    pub inner_radius: f32,
+    #[default = 100.0]
+    pub outer_radius: f32,
+    #[default(math::Angle::degrees(15.0))]
+    pub gap_angle: math::Angle,
+    pub rotation: math::Angle,
+    pub kind: SunburstKind,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl FillSunburst<'_>
+{
+    /// The outline of every wedge, counter-clockwise from the first one.
+    pub fn wedges(&self, tolerance: f32) -> Vec<Vec<math::Point>>
+    {
+        let step = std::f32::consts::PI * 2.0 / self.count.max(1) as f32;
+        let sweep = math::Angle::radians((step - self.gap_angle.radians).max(0.0));
+
+        (0..self.count)
+            .map(|i| {
+                let start = self.rotation + math::Angle::radians(step * i as f32);
+                let end = start + sweep;
+                let at = |angle: math::Angle, radius: f32| self.center + math::vector(angle.radians.cos(), angle.radians.sin()) * radius;
+
+                let mut points = match self.kind
+                {
+                    SunburstKind::Wedges => arc_points(self.center, self.outer_radius, start, sweep, tolerance),
+                    SunburstKind::Rays => vec![at(start, self.outer_radius), at(end, self.outer_radius)],
+                };
+
+                if self.inner_radius > 0.0
+                {
+                    match self.kind
+                    {
+                        SunburstKind::Wedges => points.extend(arc_points(self.center, self.inner_radius, end, -sweep, tolerance)),
+                        SunburstKind::Rays => points.extend_from_slice(&[at(end, self.inner_radius), at(start, self.inner_radius)]),
+                    }
+                }
+                else
+                {
+                    points.push(self.center);
+                }
+
+                points
+            })
+            .collect()
+    }
+}
+
+impl LyonShapeBuilder for FillSunburst<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let mut tessellator = tess::FillTessellator::new();
+
+        for wedge in self.wedges(self.options.tolerance)
+        {
+            tessellator.tessellate_polygon(Polygon { points: &wedge, closed: true }, self.options, builder)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LyonShapeOutline for FillSunburst<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        self.wedges(tolerance).into_iter().map(|points| Contour { points, is_closed: true }).collect()
+    }
+}
+
+/// A superellipse, the shape of `|x / radii.x|^exponent + |y / radii.y|^exponent = 1`.
+///
+/// An exponent of 2 gives an ellipse, and greater ones get closer to a rect with continuously curved corners,
+/// such as the squircles of app icons around 4 or 5.
+#[derive(Debug, SmartDefault)]
+pub struct FillSuperellipse<'a> {
+    pub center: math::Point,
+    #[default(math::vector(25.0, 25.0))]
+    pub radii: math::Vector,
+    #[default = 4.0]
+    pub exponent: f32,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl LyonShapeBuilder for FillSuperellipse<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &superellipse_points(self.center, self.radii, self.exponent, self.options.tolerance), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillSuperellipse<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour {
+            points: superellipse_points(self.center, self.radii, self.exponent, tolerance),
+            is_closed: true,
+        }]
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct FillTriangle<'a> {
+    #[default([ math::point(0.0, 0.0), math::point(25.0/2.0, 25.0), math::point(25.0, 0.0),])]
+    pub points: [math::Point; 3],
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions
+}
+
+impl LyonShapeBuilder for FillTriangle<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.points, closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillTriangle<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points.to_vec(), is_closed: true }]
+    }
+}
+
+/// A trapezoid with horizontal bases centered on `center`, its top base shifted along X by `skew` from being centered over the bottom one.
+#[derive(Debug, SmartDefault)]
+pub struct FillTrapezoid<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub bottom_width: f32,
+    #[default = 15.0]
+    pub top_width: f32,
+    #[default = 25.0]
+    pub height: f32,
+    pub skew: f32,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl FillTrapezoid<'_>
+{
+    /// The corners of the trapezoid, counter-clockwise from the left end of its bottom base.
+    pub fn corners(&self) -> [math::Point; 4]
+    {
+        let (bottom, top) = (self.center.y - self.height / 2.0, self.center.y + self.height / 2.0);
+        let top_center = self.center.x + self.skew / 2.0;
+        let bottom_center = self.center.x - self.skew / 2.0;
+
+        [
+            math::point(bottom_center - self.bottom_width / 2.0, bottom),
+            math::point(bottom_center + self.bottom_width / 2.0, bottom),
+            math::point(top_center + self.top_width / 2.0, top),
+            math::point(top_center - self.top_width / 2.0, top),
+        ]
+    }
+}
+
+impl LyonShapeBuilder for FillTrapezoid<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.corners(), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for FillTrapezoid<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.corners().to_vec(), is_closed: true }]
+    }
+}
+
+/// A polygon with polygonal holes in it, such as a frame or the outline of a letter like O or A.
+///
+/// The holes are cut out with the [`EvenOdd`](tess::FillRule::EvenOdd) rule whatever the fill rule of the options,
+/// so they can be wound either way, but must lie within the outer contour without overlapping each other.
+#[derive(Debug, SmartDefault)]
+pub struct FillWithHoles<'a> {
+    pub outer: Vec<math::Point>,
+    pub holes: Vec<Vec<math::Point>>,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl LyonShapeBuilder for FillWithHoles<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let mut path = path::Path::builder();
+        for points in std::iter::once(&self.outer).chain(&self.holes)
+        {
+            if points.len() > 2
+            {
+                path.add_polygon(Polygon { points, closed: true });
+            }
+        }
+
+        let options = self.options.with_fill_rule(tess::FillRule::EvenOdd);
+        tess::FillTessellator::new().tessellate_path(&path.build(), &options, builder)
+    }
+}
+
+impl LyonShapeOutline for FillWithHoles<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        std::iter::once(&self.outer)
+            .chain(&self.holes)
+            .map(|points| Contour { points: points.clone(), is_closed: true })
+            .collect()
+    }
+}
+
+/// How the corners an [`Offset`] moves edges away from are joined back together.
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum OffsetJoin
+{
+    /// The edges are extended until they meet, unless that is further than `limit` times the distance from the corner, where they are beveled.
+    #[default]
+    Miter
+    {
+        #[default = 4.0]
+        limit: f32,
+    },
+    /// An arc around the corner, keeping the same distance from it all the way.
+    Round,
+    /// A straight cut across the corner.
+    Bevel,
+}
+
+/// The fill of a shape's outline grown by `distance`, or shrunk when it is negative, such as for selection outlines or the safe zone within a border.
+///
+/// Closed contours within an odd number of the others are holes, which shrink as the shape grows, and open ones are left out.
+/// Shrinking a contour by more than half of its narrowest part makes it fold over itself.
+/// The outline is the offset contours, so it can also be stroked, such as through [`LyonMeshBuilder::with_dashed_stroke`].
+///
+/// [`LyonMeshBuilder::with_dashed_stroke`]: crate::mesh_builder::LyonMeshBuilder::with_dashed_stroke
+#[derive(Debug)]
+pub struct Offset<'a, S>
+where
+    S: LyonShapeOutline
+{
+    pub shape: S,
+    pub distance: f32,
+    pub join: OffsetJoin,
+    pub options: &'a tess::FillOptions,
+}
+
+impl<'a, S> Offset<'a, S>
+where
+    S: LyonShapeOutline
+{
+    /// Offsets a shape with mitered corners, beveled past four times the distance.
+    pub fn new(shape: S, distance: f32, options: &'a tess::FillOptions) -> Self
+    {
+        Offset { shape, distance, join: OffsetJoin::Miter { limit: 4.0 }, options }
+    }
+}
+
+impl<S> LyonShapeBuilder for Offset<'_, S>
+where
+    S: LyonShapeOutline
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let mut path = path::Path::builder();
+        for contour in self.outline(self.options.tolerance)
+        {
+            path.add_polygon(Polygon { points: &contour.points, closed: true });
+        }
+
+        tess::FillTessellator::new().tessellate_path(&path.build(), self.options, builder)
+    }
+}
+
+impl<S> LyonShapeOutline for Offset<'_, S>
+where
+    S: LyonShapeOutline
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        let contours: Vec<_> = self.shape.outline(tolerance)
+            .into_iter()
+            .filter(|contour| contour.is_closed && !contour.points.is_empty())
+            .collect();
+
+        contours.iter()
+            .enumerate()
+            .map(|(i, contour)| {
+                let distance = if is_hole(&contours, i) { -self.distance } else { self.distance };
+                offset_contour(&contour.points, distance, self.join, tolerance)
+            })
+            .filter(|points| points.len() > 2)
+            .map(|points| Contour { points, is_closed: true })
+            .collect()
+    }
+}
+
+/// A circular arc going from `start_angle` around by `sweep_angle`, counter-clockwise for positive sweeps.
+#[derive(Debug, SmartDefault)]
+pub struct StrokeArc<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub radius: f32,
+    pub start_angle: math::Angle,
+    #[default(math::Angle::degrees(90.0))]
+    pub sweep_angle: math::Angle,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
+}
+
+impl LyonShapeBuilder for StrokeArc<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &self.outline(self.options.tolerance)[0].points, closed: false },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeArc<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour {
+            points: arc_points(self.center, self.radius, self.start_angle, self.sweep_angle, tolerance),
+            is_closed: false,
+        }]
+    }
+}
+
+/// A B-spline of `degree` through the knots of its control points, which it is pulled towards without going through them.
+///
+/// Without knots, the knots are spread evenly and clamped so that the curve starts at the first point and ends at the last one.
+/// Knots given explicitly must not decrease, and there must be as many of them as points plus the degree plus one, or nothing is drawn.
+#[derive(Debug, SmartDefault)]
+pub struct StrokeBSpline<'a, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    pub points: I,
+    /// The degree of the curve, `3` for a cubic one, which is lowered to fit when there are too few points and knots aren't given.
+    #[default = 3]
+    pub degree: usize,
+    pub knots: Option<Vec<f32>>,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
+}
+
+impl<I> StrokeBSpline<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    /// The degree and knots of the curve, or `None` if the knots given don't fit the points.
+    pub fn knot_vector(&self) -> Option<(usize, Vec<f32>)>
+    {
+        let count = self.points.as_ref().len();
+
+        match &self.knots
+        {
+            Some(knots) =>
+            {
+                let fits = self.degree > 0
+                    && count > self.degree
+                    && knots.len() == count + self.degree + 1
+                    && knots.windows(2).all(|pair| pair[0] <= pair[1]);
+
+                if fits { Some((self.degree, knots.clone())) } else { None }
+            }
+            None if count < 2 => None,
+            None =>
+            {
+                let degree = self.degree.max(1).min(count - 1);
+                let spans = count - degree;
+                let knots = (0..count + degree + 1)
+                    .map(|i| (i.saturating_sub(degree).min(spans)) as f32)
+                    .collect();
+
+                Some((degree, knots))
+            }
+        }
+    }
+
+    /// The point of the curve at `t`, within the knots the curve spans, with de Boor's algorithm.
+    pub fn point_at(&self, t: f32) -> Option<math::Point>
+    {
+        let (degree, knots) = self.knot_vector()?;
+        Some(self.evaluate(degree, &knots, t))
+    }
+
+    /// The points along the curve, close enough together for it to stay within `tolerance` of it.
+    pub fn flattened(&self, tolerance: f32) -> Vec<math::Point>
+    {
+        let (degree, knots) = match self.knot_vector()
+        {
+            Some(knot_vector) => knot_vector,
+            None => return vec![],
+        };
+
+        let count = self.points.as_ref().len();
+        let curve = |t| self.evaluate(degree, &knots, t);
+        let mut points = vec![curve(knots[degree])];
+
+        for span in degree..count
+        {
+            let (start, end) = (knots[span], knots[span + 1]);
+            if start >= end
+            {
+                continue;
+            }
+
+            // A few even samples per span first, so that a curve bending back on itself isn't taken for a straight one
+            let steps = degree + 1;
+            for step in 0..steps
+            {
+                let from = start + (end - start) * step as f32 / steps as f32;
+                let to = start + (end - start) * (step + 1) as f32 / steps as f32;
+                subdivide_curve(&curve, from, to, tolerance, 16, &mut points);
+            }
+        }
+
+        points
+    }
+
+    /// Internal utility method that evaluates the curve at `t`, clamped to the knots the curve spans.
+    fn evaluate(&self, degree: usize, knots: &[f32], t: f32) -> math::Point
+    {
+        let points = self.points.as_ref();
+        let count = points.len();
+        let t = t.max(knots[degree]).min(knots[count]);
+
+        // The last span that starts at or before `t` and isn't empty
+        let span = (degree..count)
+            .rev()
+            .find(|&span| knots[span] <= t && knots[span] < knots[span + 1])
+            .unwrap_or(degree);
+
+        let mut d: Vec<_> = (0..=degree).map(|j| points[j + span - degree]).collect();
+
+        for r in 1..=degree
+        {
+            for j in (r..=degree).rev()
+            {
+                let i = j + span - degree;
+                let width = knots[i + 1 + degree - r] - knots[i];
+                let alpha = if width > 0.0 { (t - knots[i]) / width } else { 0.0 };
+                d[j] = d[j - 1].lerp(d[j], alpha);
+            }
+        }
+
+        d[degree]
+    }
+}
+
+/// Internal utility function that adds the points of a curve from `from` excluded to `to` included,
+/// halving the interval until its chord is within tolerance of the curve or `depth` halvings were made.
+fn subdivide_curve(curve: &impl Fn(f32) -> math::Point, from: f32, to: f32, tolerance: f32, depth: u32, points: &mut Vec<math::Point>)
+{
+    let middle = (from + to) / 2.0;
+    let chord = Contour { points: vec![curve(from), curve(to)], is_closed: false };
+
+    if depth > 0 && chord.distance_to(curve(middle)) > tolerance
+    {
+        subdivide_curve(curve, from, middle, tolerance, depth - 1, points);
+        subdivide_curve(curve, middle, to, tolerance, depth - 1, points);
+    }
+    else
+    {
+        points.push(curve(to));
+    }
+}
+
+impl<I> LyonShapeBuilder for StrokeBSpline<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &self.flattened(self.options.tolerance), closed: false },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl<I> LyonShapeOutline for StrokeBSpline<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.flattened(tolerance), is_closed: false }]
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct StrokeCircle<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub radius: f32,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions
+}
+
+impl LyonShapeBuilder for StrokeCircle<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_circle(
+            self.center,
+            self.radius,
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeCircle<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        circle_contours(self.center, self.radius, tolerance)
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct StrokeEllipse<'a> {
+    pub center: math::Point,
+    #[default(math::vector(40.0, 25.0))]
+    pub radii: math::Vector,
+    pub x_rotation: math::Angle,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions
+}
+
+impl LyonShapeBuilder for StrokeEllipse<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_ellipse(
+            self.center,
+            self.radii,
+            self.x_rotation,
+            Winding::Positive,
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeEllipse<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        let mut builder = path::Path::builder();
+        builder.add_ellipse(self.center, self.radii, self.x_rotation, Winding::Positive);
+
+        path_contours(&builder.build(), tolerance)
+    }
+}
+
+/// Any path lyon can build, owned or borrowed, stroked with the full stroke tessellator.
+///
+/// Paths have no meaningful default, so this is made with [`StrokePath::new`] or by giving all of its fields.
+#[derive(Debug)]
+pub struct StrokePath<'a, P>
+where
+    P: Borrow<path::Path>
+{
+    pub path: P,
+    pub options: &'a tess::StrokeOptions,
+}
+
+impl<P> StrokePath<'_, P>
+where
+    P: Borrow<path::Path>
+{
+    /// Strokes a path with the default options.
+    pub fn new(path: P) -> Self
+    {
+        StrokePath { path, options: &tess::StrokeOptions::DEFAULT }
+    }
+}
+
+impl<P> LyonShapeBuilder for StrokePath<'_, P>
+where
+    P: Borrow<path::Path>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_path(self.path.borrow(), self.options, builder)
+    }
+}
+
+impl<P> LyonShapeOutline for StrokePath<'_, P>
+where
+    P: Borrow<path::Path>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(self.path.borrow(), tolerance)
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct StrokePolyline<'a, I>
+where
+    I: IntoIterator<Item=math::Point> + Default
+{
+    pub points: I,
+    #[default = true]
+    pub is_closed: bool,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
+}
+
+impl<I> LyonShapeBuilder for StrokePolyline<'_, I>
+where
+    I: IntoIterator<Item=math::Point> + Default
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let points: Vec<_> = self.points.into_iter().collect();
+
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &points, closed: self.is_closed },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl<I> LyonShapeOutline for StrokePolyline<'_, I>
+where
+    I: IntoIterator<Item=math::Point> + Default + Clone
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points.clone().into_iter().collect(), is_closed: self.is_closed }]
+    }
+}
+
+/// The stroke of a polyline with its corners rounded, see [`FillRoundedPolygon`]. Open polylines keep sharp points at their ends.
+#[derive(Debug, SmartDefault)]
+pub struct StrokeRoundedPolygon<'a, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    pub points: I,
+    #[default = 5.0]
+    pub radius: f32,
+    /// The radius of each corner in the order of the points, with corners past its end using `radius`.
+    pub radii: Vec<f32>,
+    #[default = true]
+    pub is_closed: bool,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
+}
+
+impl<I> LyonShapeBuilder for StrokeRoundedPolygon<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_path(
+            &rounded_polygon_path(self.points.as_ref(), self.radius, &self.radii, self.is_closed),
+            self.options,
+            builder
+        )
+    }
+}
+
+impl<I> LyonShapeOutline for StrokeRoundedPolygon<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(&rounded_polygon_path(self.points.as_ref(), self.radius, &self.radii, self.is_closed), tolerance)
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct StrokeQuad<'a>
+{
+    #[default([ math::point(0.0, 0.0), math::point(0.0, 25.0), math::point(25.0, 25.0), math::point(25.0, 0.0) ])]
+    pub points: [math::Point; 4],
+    /// Open quads are only stroked along three of their sides, with caps at both ends.
+    #[default = true]
+    pub is_closed: bool,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions
+}
+
+impl LyonShapeBuilder for StrokeQuad<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &self.points, closed: self.is_closed },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeQuad<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points.to_vec(), is_closed: self.is_closed }]
+    }
+}
+
+/// A single quadratic bézier curve, stroked without building a path, such as for the edges of node graphs and other curved connectors.
+#[derive(Debug, SmartDefault)]
+pub struct StrokeQuadraticBezier<'a>
+{
+    pub from: math::Point,
+    #[default(math::point(50.0, 100.0))]
+    pub ctrl: math::Point,
+    #[default(math::point(100.0, 0.0))]
+    pub to: math::Point,
+    pub flattening: CurveFlattening,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
+}
+
+impl<'a> StrokeQuadraticBezier<'a>
+{
+    /// Makes the stroke of a curve from `from` to `to` bending towards `ctrl`, flattened the default way.
+    pub fn new(from: math::Point, ctrl: math::Point, to: math::Point, options: &'a tess::StrokeOptions) -> Self
+    {
+        StrokeQuadraticBezier { from, ctrl, to, options, ..Default::default() }
+    }
+
+    /// The curve being stroked.
+    pub fn segment(&self) -> QuadraticBezierSegment<f32>
+    {
+        QuadraticBezierSegment { from: self.from, ctrl: self.ctrl, to: self.to }
+    }
+}
+
+impl LyonShapeBuilder for StrokeQuadraticBezier<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let points: Vec<_> = self.flattening.flatten(&self.segment(), self.options.tolerance)
+            .into_iter()
+            .map(|(point, _)| point)
+            .collect();
+
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &points, closed: false },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeQuadraticBezier<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        let points = self.flattening.flatten(&self.segment(), tolerance)
+            .into_iter()
+            .map(|(point, _)| point)
+            .collect();
+
+        vec![Contour { points, is_closed: false }]
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct StrokeRect<'a> {
+    pub rect: math::Rect,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
+}
+
+impl LyonShapeBuilder for StrokeRect<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_rectangle(
+            &self.rect.to_box2d(),
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeRect<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        rect_contours(&self.rect)
+    }
+}
+
+/// Fills any number of rects in one go, such as the cells of a grid or the bars of a histogram.
+///
+/// Rects that need their own color can be added with [`LyonMeshBuilder::with_colored_rects`](crate::mesh_builder::LyonMeshBuilder::with_colored_rects) instead.
+#[derive(Debug, SmartDefault)]
+pub struct Rects<'a, I>
+where
+    I: IntoIterator<Item=math::Rect> + Default
+{
+    pub rects: I,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl<I> LyonShapeBuilder for Rects<'_, I>
+where
+    I: IntoIterator<Item=math::Rect> + Default
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let mut tessellator = tess::FillTessellator::new();
+
+        for rect in self.rects
+        {
+            tessellator.tessellate_rectangle(&rect.to_box2d(), self.options, builder)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I> LyonShapeOutline for Rects<'_, I>
+where
+    I: IntoIterator<Item=math::Rect> + Default + Clone
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        self.rects.clone().into_iter().flat_map(|rect| rect_contours(&rect)).collect()
+    }
+}
+
+/// Fills any number of polygons in one go with the same options, such as the regions of a map layer.
+///
+/// The polygons are tessellated together as a single path, so where they overlap is decided by the fill rule of the options.
+#[derive(Debug, SmartDefault)]
+pub struct Polygons<'a, I>
+where
+    I: IntoIterator + Default,
+    I::Item: AsRef<[math::Point]>
+{
+    pub polygons: I,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl<I> LyonShapeBuilder for Polygons<'_, I>
+where
+    I: IntoIterator + Default,
+    I::Item: AsRef<[math::Point]>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let mut path = path::Path::builder();
+
+        for polygon in self.polygons
+        {
+            let points = polygon.as_ref();
+
+            if points.len() > 2
+            {
+                path.add_polygon(Polygon { points, closed: true });
+            }
+        }
+
+        tess::FillTessellator::new().tessellate_path(&path.build(), self.options, builder)
+    }
+}
+
+impl<I> LyonShapeOutline for Polygons<'_, I>
+where
+    I: IntoIterator + Default + Clone,
+    I::Item: AsRef<[math::Point]>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        self.polygons.clone().into_iter()
+            .map(|polygon| Contour { points: polygon.as_ref().to_vec(), is_closed: true })
+            .collect()
+    }
+}
+
+/// Which way the corners of a hexagon point, see [`Hexagon`] and [`HexGrid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmartDefault)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum HexOrientation
+{
+    /// A corner at the top and the bottom, with the rows of a grid fitting into each other.
+    #[default]
+    PointyTop,
+    /// A flat side at the top and the bottom, with the columns of a grid fitting into each other.
+    FlatTop,
+}
+
+impl HexOrientation
+{
+    /// The angle of the first corner of a hexagon, the others following counter-clockwise.
+    pub fn rotation(self) -> math::Angle
+    {
+        match self
+        {
+            HexOrientation::PointyTop => math::Angle::degrees(30.0),
+            HexOrientation::FlatTop => math::Angle::zero(),
+        }
+    }
+}
+
+/// A regular hexagon whose corners all lie on a circle of `size`, such as a tile of a hex map.
+#[derive(Debug, SmartDefault)]
+pub struct Hexagon<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub size: f32,
+    pub orientation: HexOrientation,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl Hexagon<'_>
+{
+    /// The corners of the hexagon, counter-clockwise from the one at the rotation of its orientation.
+    pub fn corners(&self) -> Vec<math::Point>
+    {
+        regular_polygon_points(self.center, self.size, 6, self.orientation.rotation())
+    }
+}
+
+impl LyonShapeBuilder for Hexagon<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.corners(), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for Hexagon<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.corners(), is_closed: true }]
+    }
+}
+
+/// A grid of `columns` by `rows` hexagons in one go, the first of which is centered on `origin`, such as a hex map.
+///
+/// The grid uses offset coordinates: odd rows are shifted right by half a hexagon with pointy tops, and odd columns up with flat tops.
+/// A `gap` shrinks every hexagon to leave that much space between neighbours.
+#[derive(Debug, SmartDefault)]
+pub struct HexGrid<'a> {
+    pub origin: math::Point,
+    #[default = 25.0]
+    pub size: f32,
+    pub orientation: HexOrientation,
+    #[default = 8]
+    pub columns: usize,
+    #[default = 8]
+    pub rows: usize,
+    pub gap: f32,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl HexGrid<'_>
+{
+    /// The center of the hexagon at a column and row of the grid.
+    pub fn center(&self, column: usize, row: usize) -> math::Point
+    {
+        let (across, along) = (3.0f32.sqrt() * self.size, 1.5 * self.size);
+        let shift = |odd: bool| if odd { 0.5 } else { 0.0 };
+
+        match self.orientation
+        {
+            HexOrientation::PointyTop =>
+                self.origin + math::vector(across * (column as f32 + shift(row % 2 == 1)), along * row as f32),
+            HexOrientation::FlatTop =>
+                self.origin + math::vector(along * column as f32, across * (row as f32 + shift(column % 2 == 1))),
+        }
+    }
+
+    /// The corners of every hexagon of the grid, row by row.
+    pub fn hexagons(&self) -> Vec<Vec<math::Point>>
+    {
+        // Moving the sides of a hexagon in by half the gap moves its corners in by a little more
+        let size = (self.size - self.gap / 3.0f32.sqrt()).max(0.0);
+
+        (0..self.rows)
+            .flat_map(|row| (0..self.columns).map(move |column| (column, row)))
+            .map(|(column, row)| regular_polygon_points(self.center(column, row), size, 6, self.orientation.rotation()))
+            .collect()
+    }
+}
+
+impl LyonShapeBuilder for HexGrid<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let mut tessellator = tess::FillTessellator::new();
+
+        for hexagon in self.hexagons()
+        {
+            tessellator.tessellate_polygon(Polygon { points: &hexagon, closed: true }, self.options, builder)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LyonShapeOutline for HexGrid<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        self.hexagons().into_iter().map(|points| Contour { points, is_closed: true }).collect()
+    }
+}
+
+/// The lines of a grid over a rect in one go, one every `cell_size` from the rect's minimum, such as for graph paper or an editor's background.
+///
+/// The lines at the edges of the rect are only there when the cells fit it exactly.
+#[derive(Debug, SmartDefault)]
+pub struct StrokeGrid<'a> {
+    #[default(math::Rect::new(math::point(0.0, 0.0), math::size(100.0, 100.0)))]
+    pub rect: math::Rect,
+    #[default(math::size(10.0, 10.0))]
+    pub cell_size: math::Size,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
+}
+
+impl StrokeGrid<'_>
+{
+    /// The ends of every line of the grid, the vertical lines from left to right followed by the horizontal ones from bottom to top.
+    pub fn lines(&self) -> Vec<[math::Point; 2]>
+    {
+        let (min, max) = (self.rect.min(), self.rect.max());
+
+        // Just enough for the far edge to be kept when the cells fit the rect exactly despite rounding
+        let slack = |cell: f32| cell * 1e-4;
+        let offsets = |length: f32, cell: f32| (0..)
+            .map(move |i| i as f32 * cell)
+            .take_while(move |&offset| cell > 0.0 && offset <= length + slack(cell));
+
+        offsets(self.rect.width(), self.cell_size.width)
+            .map(|x| [math::point(min.x + x, min.y), math::point(min.x + x, max.y)])
+            .chain(offsets(self.rect.height(), self.cell_size.height)
+                .map(|y| [math::point(min.x, min.y + y), math::point(max.x, min.y + y)]))
+            .collect()
+    }
+}
+
+impl LyonShapeBuilder for StrokeGrid<'_>
 {
     fn build(self, builder: &mut BevyBuffersBuilder)
     {
-        let _ = basic_shapes::fill_convex_polyline(
-            self.points.into_iter(),
-            self.options, 
-            builder
-        );
+        let _ = self.try_build(builder);
     }
-}
 
-// Debug is not derived because tess::FillTessellator does not derive it.
-// We can hack around now having Default, but hacking around not having Debug is a bit much...
-#[derive(SmartDefault)]
-pub struct FillPolyline<'a, I>
-where
-    I: IntoIterator<Item=math::Point> + Default
-{
-    pub points: I,
-    #[default(tess::FillTessellator::new())]
-    pub tessellator: tess::FillTessellator,
-    #[default(&tess::FillOptions::DEFAULT)]
-    pub options: &'a tess::FillOptions,
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        let mut tessellator = tess::StrokeTessellator::new();
+
+        for line in self.lines()
+        {
+            tessellator.tessellate_polygon(Polygon { points: &line, closed: false }, self.options, builder)?;
+        }
+
+        Ok(())
+    }
 }
 
-impl<I> LyonShapeBuilder for FillPolyline<'_, I>
-where
-    I: IntoIterator<Item=math::Point> + Default
+impl LyonShapeOutline for StrokeGrid<'_>
 {
-    fn build(mut self, builder: &mut BevyBuffersBuilder)
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
     {
-        let _ = basic_shapes::fill_polyline(
-            self.points, 
-            &mut self.tessellator, 
-            self.options, 
-            builder
-        );
+        self.lines().iter().map(|line| Contour { points: line.to_vec(), is_closed: false }).collect()
     }
 }
 
-// TODO: Check what happens when the points aren't at right angles!
+/// A polygon with `sides` equal sides, such as a hexagon, whose corners all lie on a circle of `radius`.
 #[derive(Debug, SmartDefault)]
-pub struct FillQuad<'a> {
-    #[default([ math::point(0.0, 0.0), math::point(0.0, 25.0), math::point(25.0, 25.0), math::point(25.0, 0.0) ])]
-    points: [math::Point; 4],
-    #[default(&tess::FillOptions::DEFAULT)]
-    options: &'a tess::FillOptions,
+pub struct StrokeRegularPolygon<'a> {
+    pub center: math::Point,
+    #[default = 25.0]
+    pub radius: f32,
+    #[default = 6]
+    pub sides: usize,
+    /// The angle of the first corner, the others following counter-clockwise.
+    pub rotation: math::Angle,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
 }
 
-impl LyonShapeBuilder for FillQuad<'_>
+impl StrokeRegularPolygon<'_>
+{
+    /// The corners of the polygon, counter-clockwise from the one at `rotation`.
+    pub fn points(&self) -> Vec<math::Point>
+    {
+        regular_polygon_points(self.center, self.radius, self.sides, self.rotation)
+    }
+}
+
+impl LyonShapeBuilder for StrokeRegularPolygon<'_>
 {
-    fn build(self, builder: &mut BevyBuffersBuilder) 
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
     {
-        let _ = basic_shapes::fill_quad(
-            self.points[0],
-            self.points[1],
-            self.points[2],
-            self.points[3],
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &self.points(), closed: true },
             self.options,
             builder
-        );
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeRegularPolygon<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points(), is_closed: true }]
     }
 }
 
+/// A rect turned by `angle` around its center, such as an oriented bounding box.
 #[derive(Debug, SmartDefault)]
-pub struct FillRect<'a> {
-    rect: math::Rect,
-    #[default(&tess::FillOptions::DEFAULT)]
-    options: &'a tess::FillOptions,
+pub struct StrokeRotatedRect<'a> {
+    pub center: math::Point,
+    #[default(math::size(25.0, 25.0))]
+    pub size: math::Size,
+    pub angle: math::Angle,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
 }
 
-impl LyonShapeBuilder for FillRect<'_>
+impl StrokeRotatedRect<'_>
+{
+    /// The corners of the rect, counter-clockwise from the one that was at its minimum before the rotation.
+    pub fn corners(&self) -> [math::Point; 4]
+    {
+        rotated_rect_corners(self.center, self.size, self.angle)
+    }
+}
+
+impl LyonShapeBuilder for StrokeRotatedRect<'_>
 {
     fn build(self, builder: &mut BevyBuffersBuilder)
     {
-        let _ = basic_shapes::fill_rectangle(
-            &self.rect,
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &self.corners(), closed: true },
             self.options,
             builder
-        );
+        )
     }
 }
 
-// Debug is not derived because basic_shapes::BorderRadii does not derive it.
-// We can hack around now having Default, but hacking around not having Debug is a bit much...
-#[derive(SmartDefault)]
-pub struct FillRoundedRect<'a> {
+impl LyonShapeOutline for StrokeRotatedRect<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.corners().to_vec(), is_closed: true }]
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct StrokeRoundedRect<'a> {
     pub rect: math::Rect,
-    #[default(basic_shapes::BorderRadii::new_all_same(10.0))]
-    pub radii: basic_shapes::BorderRadii,
-    #[default(&tess::FillOptions::DEFAULT)]
-    pub options: &'a tess::FillOptions,
+    #[default(BorderRadii::new(10.0))]
+    pub radii: BorderRadii,
+    #[default(&tess::StrokeOptions::DEFAULT)]
+    pub options: &'a tess::StrokeOptions,
 }
 
-impl LyonShapeBuilder for FillRoundedRect<'_>
+impl LyonShapeBuilder for StrokeRoundedRect<'_>
 {
     fn build(self, builder: &mut BevyBuffersBuilder)
     {
-        let _ = basic_shapes::fill_rounded_rectangle(
-            &self.rect,
-            &self.radii,
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_path(
+            &rounded_rect_path(&self.rect, &self.radii),
             self.options,
             builder
-        );
+        )
     }
 }
 
-#[derive(Debug, SmartDefault)]
-pub struct StrokeCircle<'a> {
-    pub center: math::Point,
-    #[default = 25.0]
-    pub radius: f32,
-    #[default(&tess::StrokeOptions::DEFAULT)]
-    pub options: &'a tess::StrokeOptions
+impl LyonShapeOutline for StrokeRoundedRect<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(&rounded_rect_path(&self.rect, &self.radii), tolerance)
+    }
 }
 
-impl LyonShapeBuilder for StrokeCircle<'_>
+/// How the radius of a [`StrokeSpiral`] grows as it turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmartDefault)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum SpiralKind
 {
-    fn build(self, builder: &mut BevyBuffersBuilder) {
-        let _ = basic_shapes::stroke_circle(
-            self.center,
-            self.radius,
-            self.options,
-            builder
-        );
-    }
+    /// The radius grows by the same distance every turn, with evenly spaced turns like a coiled rope.
+    #[default]
+    Archimedean,
+    /// The radius grows by the same factor every turn, with turns spreading out like a nautilus shell.
+    Logarithmic,
 }
 
+/// A spiral turning `turns` times counter-clockwise around its center, from `start_radius` at `start_angle`.
+///
+/// The radius grows by `growth` every turn, which is a distance for archimedean spirals and a factor for logarithmic ones.
+/// Negative turns wind the spiral clockwise instead.
 #[derive(Debug, SmartDefault)]
-pub struct StrokeEllipse<'a> {
+pub struct StrokeSpiral<'a> {
     pub center: math::Point,
-    #[default(math::vector(40.0, 25.0))]
-    pub radii: math::Vector,
-    pub x_rotation: math::Angle,
+    #[default = 5.0]
+    pub start_radius: f32,
+    #[default = 10.0]
+    pub growth: f32,
+    #[default = 3.0]
+    pub turns: f32,
+    pub start_angle: math::Angle,
+    pub kind: SpiralKind,
     #[default(&tess::StrokeOptions::DEFAULT)]
-    pub options: &'a tess::StrokeOptions
+    pub options: &'a tess::StrokeOptions,
 }
 
-impl LyonShapeBuilder for StrokeEllipse<'_>
+impl StrokeSpiral<'_>
+{
+    /// The radius of the spiral after a number of turns.
+    pub fn radius_at(&self, turns: f32) -> f32
+    {
+        match self.kind
+        {
+            SpiralKind::Archimedean => self.start_radius + self.growth * turns,
+            SpiralKind::Logarithmic => self.start_radius * self.growth.powf(turns),
+        }
+    }
+
+    /// The points along the spiral from its start, close enough together for it to stay within `tolerance` of its curve.
+    ///
+    /// Spirals with an infinite or NaN number of turns have no points.
+    pub fn points(&self, tolerance: f32) -> Vec<math::Point>
+    {
+        if !self.turns.is_finite()
+        {
+            return vec![];
+        }
+
+        let end = self.turns.abs();
+        let direction = self.turns.signum();
+        let mut turns = 0.0;
+        let mut points = vec![];
+
+        loop
+        {
+            let angle = self.start_angle.radians + turns * direction * std::f32::consts::PI * 2.0;
+            let radius = self.radius_at(turns);
+            points.push(self.center + math::vector(angle.cos(), angle.sin()) * radius);
+
+            if turns >= end
+            {
+                return points;
+            }
+
+            // The step a circle of the current radius needs, with at most a few thousand steps for spirals shrinking to nothing
+            let step = ((8.0 * tolerance / radius.abs().max(f32::EPSILON)).sqrt() / (std::f32::consts::PI * 2.0)).max(end / 4096.0);
+            turns = (turns + step).min(end);
+        }
+    }
+}
+
+impl LyonShapeBuilder for StrokeSpiral<'_>
 {
     fn build(self, builder: &mut BevyBuffersBuilder)
     {
-        let _ = basic_shapes::stroke_ellipse(
-            self.center,
-            self.radii,
-            self.x_rotation,
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &self.points(self.options.tolerance), closed: false },
             self.options,
             builder
-        );
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeSpiral<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points(tolerance), is_closed: false }]
     }
 }
 
+/// A Catmull-Rom spline going smoothly through every one of its points, such as for a patrol route through waypoints.
 #[derive(Debug, SmartDefault)]
-pub struct StrokePolyline<'a, I>
+pub struct StrokeSpline<'a, I>
 where
-    I: IntoIterator<Item=math::Point> + Default
+    I: AsRef<[math::Point]> + Default
 {
     pub points: I,
-    #[default = true]
+    /// How far the curve bulges out between the points, `0.5` being the usual Catmull-Rom spline and `0.0` a polyline.
+    #[default = 0.5]
+    pub tension: f32,
     pub is_closed: bool,
     #[default(&tess::StrokeOptions::DEFAULT)]
     pub options: &'a tess::StrokeOptions,
 }
 
-impl<I> LyonShapeBuilder for StrokePolyline<'_, I>
+impl<I> LyonShapeBuilder for StrokeSpline<'_, I>
 where
-    I: IntoIterator<Item=math::Point> + Default
+    I: AsRef<[math::Point]> + Default
 {
     fn build(self, builder: &mut BevyBuffersBuilder)
     {
-        let _ = basic_shapes::stroke_polyline(
-            self.points,
-            self.is_closed,
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_path(
+            &spline_path(self.points.as_ref(), self.tension, self.is_closed),
             self.options,
             builder
-        );
+        )
+    }
+}
 
+impl<I> LyonShapeOutline for StrokeSpline<'_, I>
+where
+    I: AsRef<[math::Point]> + Default
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(&spline_path(self.points.as_ref(), self.tension, self.is_closed), tolerance)
     }
 }
 
+/// A star with `points` branches, whose outer points lie on a circle of `outer_radius` and inner corners on one of `inner_radius`.
 #[derive(Debug, SmartDefault)]
-pub struct StrokeQuad<'a>
-{
-    #[default([ math::point(0.0, 0.0), math::point(0.0, 25.0), math::point(25.0, 25.0), math::point(25.0, 0.0) ])]
-    pub points: [math::Point; 4],
+pub struct StrokeStar<'a> {
+    pub center: math::Point,
+    #[default = 5]
+    pub points: usize,
+    #[default = 10.0]
+    pub inner_radius: f32,
+    #[default = 25.0]
+    pub outer_radius: f32,
+    /// The angle of the first outer point, the others following counter-clockwise.
+    pub rotation: math::Angle,
     #[default(&tess::StrokeOptions::DEFAULT)]
-    pub options: &'a tess::StrokeOptions
+    pub options: &'a tess::StrokeOptions,
 }
 
-impl LyonShapeBuilder for StrokeQuad<'_>
+impl StrokeStar<'_>
+{
+    /// The corners of the star, counter-clockwise from the outer point at `rotation`, alternating between outer points and inner corners.
+    pub fn corners(&self) -> Vec<math::Point>
+    {
+        star_points(self.center, self.points, self.inner_radius, self.outer_radius, self.rotation)
+    }
+}
+
+impl LyonShapeBuilder for StrokeStar<'_>
 {
     fn build(self, builder: &mut BevyBuffersBuilder)
     {
-        let _ = basic_shapes::stroke_quad(
-            self.points[0],
-            self.points[1],
-            self.points[2],
-            self.points[3],
-            self.options, 
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &self.corners(), closed: true },
+            self.options,
             builder
-        );
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeStar<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.corners(), is_closed: true }]
     }
 }
 
 #[derive(Debug, SmartDefault)]
-pub struct StrokeRect<'a> {
-    rect: math::Rect,
+pub struct StrokeTriangle<'a> {
+    #[default([ math::point(0.0, 0.0), math::point(25.0/2.0, 25.0), math::point(25.0, 0.0),])]
+    pub points: [math::Point; 3],
+    /// Open triangles are only stroked along two of their sides, with caps at both ends.
+    #[default = true]
+    pub is_closed: bool,
     #[default(&tess::StrokeOptions::DEFAULT)]
-    options: &'a tess::StrokeOptions,
+    pub options: &'a tess::StrokeOptions
 }
 
-impl LyonShapeBuilder for StrokeRect<'_>
+impl LyonShapeBuilder for StrokeTriangle<'_>
 {
     fn build(self, builder: &mut BevyBuffersBuilder)
     {
-        let _ = basic_shapes::stroke_rectangle(
-            &self.rect,
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &self.points, closed: self.is_closed },
             self.options,
             builder
-        );
+        )
     }
 }
 
-// Debug is not derived because basic_shapes::BorderRadii does not derive it.
-// We can hack around now having Default, but hacking around not having Debug is a bit much...
-#[derive(SmartDefault)]
-pub struct StrokeRoundedRect<'a> {
-    pub rect: math::Rect,
-    #[default(basic_shapes::BorderRadii::new_all_same(10.0))]
-    pub radii: basic_shapes::BorderRadii,
-    #[default(&tess::StrokeOptions::DEFAULT)]
-    pub options: &'a tess::StrokeOptions,
+impl LyonShapeOutline for StrokeTriangle<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points.to_vec(), is_closed: self.is_closed }]
+    }
 }
 
-impl LyonShapeBuilder for StrokeRoundedRect<'_>
+/// The periodic curves a [`StrokeWave`] can follow, each going between `-1.0` and `1.0` once per period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmartDefault)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum Waveform
 {
-    fn build(self, builder: &mut BevyBuffersBuilder)
+    #[default]
+    Sine,
+    /// Straight slopes up and down between sharp peaks, starting from the middle upwards like a sine.
+    Triangle,
+    /// Flat tops and bottoms joined by straight drops, high for the first half of each period.
+    Square,
+    /// A straight slope up over the whole period, followed by a straight drop.
+    Sawtooth,
+}
+
+impl Waveform
+{
+    /// The height of the curve, `periods` into it.
+    pub fn value(self, periods: f32) -> f32
     {
-        let _ = basic_shapes::stroke_rounded_rectangle(
-            &self.rect,
-            &self.radii,
-            self.options,
-            builder
-        );
+        let t = periods - periods.floor();
+
+        match self
+        {
+            Waveform::Sine => (t * std::f32::consts::PI * 2.0).sin(),
+            Waveform::Triangle => 1.0 - (4.0 * (t - 0.25).abs()).min(4.0 * (t - 1.25).abs()),
+            Waveform::Square => if t < 0.5 { 1.0 } else { -1.0 },
+            Waveform::Sawtooth => t * 2.0 - 1.0,
+        }
+    }
+
+    /// Internal utility function that gives where the straight curves turn within a period, with their heights just before and just after.
+    fn corners(self) -> &'static [(f32, f32, f32)]
+    {
+        match self
+        {
+            Waveform::Sine => &[],
+            Waveform::Triangle => &[(0.25, 1.0, 1.0), (0.75, -1.0, -1.0)],
+            Waveform::Square => &[(0.0, -1.0, 1.0), (0.5, 1.0, -1.0)],
+            Waveform::Sawtooth => &[(0.0, 1.0, -1.0)],
+        }
     }
 }
 
+/// A wave running from `from` to `to`, `amplitude` to either side of the straight line between them, such as a water line or a signal.
+///
+/// The wave goes through `frequency` periods per unit of length, starting `phase` into its first period, and swings to the left of the line first.
 #[derive(Debug, SmartDefault)]
-pub struct StrokeTriangle<'a> {
-    #[default([ math::point(0.0, 0.0), math::point(25.0/2.0, 25.0), math::point(25.0, 0.0),])]
-    pub points: [math::Point; 3],
+pub struct StrokeWave<'a> {
+    pub from: math::Point,
+    #[default(math::point(100.0, 0.0))]
+    pub to: math::Point,
+    #[default = 5.0]
+    pub amplitude: f32,
+    #[default = 0.1]
+    pub frequency: f32,
+    pub phase: math::Angle,
+    pub waveform: Waveform,
     #[default(&tess::StrokeOptions::DEFAULT)]
-    pub options: &'a tess::StrokeOptions
+    pub options: &'a tess::StrokeOptions,
 }
 
-impl LyonShapeBuilder for StrokeTriangle<'_>
+impl StrokeWave<'_>
+{
+    /// The points along the wave, exactly at the corners of straight waveforms and close enough together for sines to stay within `tolerance` of their curve.
+    ///
+    /// A sine gets at most 16384 points, and so does a straight waveform of more periods than that. A wave with no finite end has no points.
+    pub fn points(&self, tolerance: f32) -> Vec<math::Point>
+    {
+        let length = (self.to - self.from).length();
+        let direction = (self.to - self.from).try_normalize().unwrap_or(math::vector(1.0, 0.0));
+        let side = math::vector(-direction.y, direction.x) * self.amplitude;
+
+        let start = self.phase.radians / (std::f32::consts::PI * 2.0);
+        let end = start + length * self.frequency;
+        if !end.is_finite()
+        {
+            return vec![];
+        }
+
+        let at = |periods: f32, value: f32| {
+            let along = if self.frequency > 0.0 { (periods - start) / self.frequency } else { 0.0 };
+            self.from + direction * along + side * value
+        };
+
+        let mut points = vec![at(start, self.waveform.value(start))];
+
+        // Going through every corner of that many periods would never end, so long straight waveforms are sampled like sines
+        if self.waveform == Waveform::Sine || end - start > 16384.0
+        {
+            // The curvature of a sine peaks at its crests, which sets the step along the whole wave
+            let curvature = self.amplitude.abs() * (self.frequency * std::f32::consts::PI * 2.0).powi(2);
+            let step = (8.0 * tolerance / curvature.max(f32::EPSILON)).sqrt() * self.frequency;
+            let count = ((end - start) / step.max(f32::EPSILON)).ceil().max(1.0).min(16384.0) as usize;
+
+            points.extend((1..count).map(|i| {
+                let periods = start + (end - start) * i as f32 / count as f32;
+                at(periods, self.waveform.value(periods))
+            }));
+        }
+        else
+        {
+            for period in start.floor() as i64..=end.ceil() as i64
+            {
+                for &(offset, before, after) in self.waveform.corners()
+                {
+                    let periods = period as f32 + offset;
+
+                    if periods > start && periods < end
+                    {
+                        points.push(at(periods, before));
+
+                        if after != before
+                        {
+                            points.push(at(periods, after));
+                        }
+                    }
+                }
+            }
+        }
+
+        // A wave ending right on a jump ends on the side it comes from
+        let end_offset = end - end.floor();
+        let end_value = self.waveform.corners().iter()
+            .find(|&&(offset, ..)| offset == end_offset)
+            .map_or_else(|| self.waveform.value(end), |&(_, before, _)| before);
+
+        points.push(at(end, end_value));
+        points
+    }
+}
+
+impl LyonShapeBuilder for StrokeWave<'_>
 {
     fn build(self, builder: &mut BevyBuffersBuilder)
     {
-        let _ = basic_shapes::stroke_triangle(
-            self.points[0],
-            self.points[1],
-            self.points[2],
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::StrokeTessellator::new().tessellate_polygon(
+            Polygon { points: &self.points(self.options.tolerance), closed: false },
             self.options,
             builder
-        );
+        )
+    }
+}
+
+impl LyonShapeOutline for StrokeWave<'_>
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points(tolerance), is_closed: false }]
+    }
+}
+
+/// A path parsed from SVG path data, such as `"M 10 10 C 20 20, 40 20, 50 10 Z"` pasted out of a vector editor, to be filled or stroked.
+///
+/// SVG's Y axis points down while bevy's points up, so paths come out upside down unless they are flipped,
+/// such as through [`LyonMeshBuilder::with_transformed`](crate::mesh_builder::LyonMeshBuilder::with_transformed).
+/// Only available with the `svg` feature.
+#[cfg(feature = "svg")]
+#[derive(Debug, Clone, Default)]
+pub struct SvgPathShape
+{
+    pub path: path::Path,
+}
+
+#[cfg(feature = "svg")]
+impl SvgPathShape
+{
+    /// Parses SVG path data, the contents of the `d` attribute of a `<path>` element.
+    pub fn parse(data: &str) -> Result<Self, lyon::extra::parser::ParseError>
+    {
+        use lyon::extra::parser::{
+            ParserOptions,
+            PathParser,
+            Source,
+        };
+
+        let mut builder = path::Path::builder_with_attributes(0);
+        PathParser::new().parse(&ParserOptions::DEFAULT, &mut Source::new(data.chars()), &mut builder)?;
+
+        Ok(SvgPathShape { path: builder.build() })
+    }
+
+    /// The fill of the path.
+    pub fn fill<'a>(&'a self, options: &'a tess::FillOptions) -> FillPath<'a, &'a path::Path>
+    {
+        FillPath { path: &self.path, options }
+    }
+
+    /// The stroke of the path.
+    pub fn stroke<'a>(&'a self, options: &'a tess::StrokeOptions) -> StrokePath<'a, &'a path::Path>
+    {
+        StrokePath { path: &self.path, options }
+    }
+}
+
+#[cfg(feature = "svg")]
+impl LyonShapeOutline for SvgPathShape
+{
+    fn outline(&self, tolerance: f32) -> Vec<Contour>
+    {
+        path_contours(&self.path, tolerance)
     }
 }
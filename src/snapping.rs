@@ -0,0 +1,96 @@
+//! Snapping, for editors built on the crate to author clean geometry.
+//!
+//! # Overview
+//!
+//! A [`Snapping`] snaps points to the corners of a grid and angles to multiples of an increment, either or both.
+//! It is meant to be applied to the inputs of shapes as they are authored, such as the corners of a polygon being placed,
+//! or to the pointer while something is being dragged, like the plugin's [`SelectionDrag`] does.
+//! The default snapping leaves everything as it is.
+//!
+//! [`SelectionDrag`]: crate::plugin::SelectionDrag
+
+use super::math;
+
+/// The grid and angles to snap to, see the [module level documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Snapping
+{
+    /// The size of the cells of the grid points snap to, if they snap.
+    pub grid: Option<math::Size>,
+    /// A corner of the grid, which doesn't have to be the origin of the world.
+    pub origin: math::Point,
+    /// The increment angles snap to, if they snap.
+    pub angle_increment: Option<math::Angle>,
+}
+
+impl Snapping
+{
+    /// Makes a snapping to a grid of square cells, with a corner at the origin of the world.
+    pub fn grid(spacing: f32) -> Self
+    {
+        Snapping { grid: Some(math::size(spacing, spacing)), ..Default::default() }
+    }
+
+    /// Makes a snapping to multiples of an angle.
+    pub fn angles(increment: math::Angle) -> Self
+    {
+        Snapping { angle_increment: Some(increment), ..Default::default() }
+    }
+
+    /// Snaps angles to multiples of an increment too.
+    pub fn with_angle_increment(mut self, increment: math::Angle) -> Self
+    {
+        self.angle_increment = Some(increment);
+        self
+    }
+
+    /// The corner of the grid nearest to a point.
+    pub fn snap_point(&self, point: math::Point) -> math::Point
+    {
+        match self.grid
+        {
+            Some(cell) if cell.width > 0.0 && cell.height > 0.0 =>
+            {
+                let offset = point - self.origin;
+                self.origin + math::vector((offset.x / cell.width).round() * cell.width, (offset.y / cell.height).round() * cell.height)
+            }
+            _ => point,
+        }
+    }
+
+    /// Snaps every point in place, such as the corners of a polygon.
+    pub fn snap_points(&self, points: &mut [math::Point])
+    {
+        for point in points
+        {
+            *point = self.snap_point(*point);
+        }
+    }
+
+    /// The multiple of the increment nearest to an angle.
+    pub fn snap_angle(&self, angle: math::Angle) -> math::Angle
+    {
+        match self.angle_increment
+        {
+            Some(increment) if increment.radians != 0.0 =>
+                math::Angle::radians((angle.radians / increment.radians).round() * increment.radians),
+            _ => angle,
+        }
+    }
+
+    /// Where to put the end of a segment from `from` towards `to` so that its direction snaps, keeping its length,
+    /// such as to draw lines at multiples of 45 degrees.
+    pub fn snap_direction(&self, from: math::Point, to: math::Point) -> math::Point
+    {
+        let offset = to - from;
+        let angle = self.snap_angle(offset.angle_from_x_axis());
+
+        from + math::Vector::from_angle_and_length(angle, offset.length())
+    }
+
+    /// The rect between the snapped corners of a rect.
+    pub fn snap_rect(&self, rect: &math::Rect) -> math::Rect
+    {
+        math::Rect::from_points([self.snap_point(rect.min()), self.snap_point(rect.max())])
+    }
+}
@@ -0,0 +1,148 @@
+//! Territories, the regions of a map owned by each faction, painted in grand strategy style.
+//!
+//! # Overview
+//!
+//! [`Territories`] are made from the cells owned by each faction, either the cells of a grid or any polygons.
+//! The cells of a faction are merged into regions, whose outlines are smoothed by cutting their corners a few times over.
+//! Every faction gets a translucent fill of its regions and a solid border around them, each faction being a shape of its own,
+//! so that all the factions of a map are two meshes however many there are.
+//!
+//! Borders shared by two factions are smoothed the same way from both sides, so they line up,
+//! except around the points where three or more factions meet.
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::backend::Mesh;
+use super::mesh_builder::{
+    debug_color,
+    LyonMeshBuilder,
+};
+use super::shapes::{
+    smooth_contour,
+    union_contours,
+    Contour,
+    LyonShapeOutline,
+    Polygons,
+    StrokePolyline,
+};
+
+/// The territories of every faction of a map, see the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct Territories
+{
+    /// The cells owned by each faction, indexed by faction.
+    pub factions: Vec<Vec<Contour>>,
+    /// The color of each faction, with factions past its end getting a distinct color picked from their index.
+    pub colors: Vec<[f32; 4]>,
+    /// How opaque the fills are compared to the color of their faction, which their borders are fully.
+    #[default = 0.4]
+    pub fill_opacity: f32,
+    #[default = 2.0]
+    pub border_width: f32,
+    /// How many times the corners of the regions are cut, each doubling the number of their points.
+    #[default = 2]
+    pub smoothing: usize,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Territories
+{
+    /// Makes the territories of a grid of `columns` cells per row, from the cell at `origin` along X then Y, owned by the faction at their index if any.
+    pub fn from_grid(origin: math::Point, cell_size: math::Size, columns: usize, owners: &[Option<usize>]) -> Self
+    {
+        let mut factions: Vec<Vec<Contour>> = vec![];
+
+        for (i, owner) in owners.iter().enumerate()
+        {
+            if let Some(faction) = *owner
+            {
+                if faction >= factions.len()
+                {
+                    factions.resize(faction + 1, vec![]);
+                }
+
+                let min = origin + math::vector((i % columns.max(1)) as f32 * cell_size.width, (i / columns.max(1)) as f32 * cell_size.height);
+                let max = min + cell_size.to_vector();
+
+                factions[faction].push(Contour {
+                    points: vec![min, math::point(max.x, min.y), max, math::point(min.x, max.y)],
+                    is_closed: true,
+                });
+            }
+        }
+
+        Territories { factions, ..Default::default() }
+    }
+
+    /// Gives a faction a cell in the shape of a shape's outline, flattened at the tolerance of the territories.
+    pub fn with_cell(mut self, faction: usize, shape: impl LyonShapeOutline) -> Self
+    {
+        if faction >= self.factions.len()
+        {
+            self.factions.resize(faction + 1, vec![]);
+        }
+
+        self.factions[faction].extend(shape.outline(self.tolerance));
+        self
+    }
+
+    /// The color of a faction.
+    pub fn color(&self, faction: usize) -> [f32; 4]
+    {
+        self.colors.get(faction).copied().unwrap_or_else(|| debug_color(faction))
+    }
+
+    /// The smoothed outlines of the regions of a faction, counter-clockwise around their outsides and clockwise around the gaps within them.
+    pub fn outlines(&self, faction: usize) -> Vec<Contour>
+    {
+        let cells = self.factions.get(faction).map_or(&[][..], |cells| cells.as_slice());
+
+        union_contours(cells.iter().map(|cell| cell.points.as_slice()))
+            .into_iter()
+            .map(|contour| Contour { points: smooth_contour(contour.points, self.smoothing), is_closed: true })
+            .collect()
+    }
+
+    /// Tessellates the fills of the factions into a mesh builder, with each faction as a shape of its own in the order of the factions.
+    pub fn fills_builder(&self) -> LyonMeshBuilder
+    {
+        let options = tess::FillOptions::tolerance(self.tolerance).with_fill_rule(tess::FillRule::NonZero);
+
+        (0..self.factions.len()).fold(LyonMeshBuilder::new(), |builder, faction| {
+            let [r, g, b, a] = self.color(faction);
+            let polygons: Vec<_> = self.outlines(faction).into_iter().map(|contour| contour.points).collect();
+
+            builder.with_colored(Polygons { polygons, options: &options }, [r, g, b, a * self.fill_opacity])
+        })
+    }
+
+    /// Tessellates the borders of the factions into a mesh builder, with each of their regions' outlines as a shape of its own.
+    pub fn borders_builder(&self) -> LyonMeshBuilder
+    {
+        let options = tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.border_width);
+
+        (0..self.factions.len()).fold(LyonMeshBuilder::new(), |builder, faction| {
+            let color = self.color(faction);
+
+            self.outlines(faction).into_iter().fold(builder, |builder, contour| {
+                builder.with_colored(StrokePolyline { points: contour.points, is_closed: true, options: &options }, color)
+            })
+        })
+    }
+
+    /// Builds the mesh of the fills.
+    pub fn build_fills(&self) -> Mesh
+    {
+        self.fills_builder().build()
+    }
+
+    /// Builds the mesh of the borders.
+    pub fn build_borders(&self) -> Mesh
+    {
+        self.borders_builder().build()
+    }
+}
@@ -0,0 +1,152 @@
+//! Cooperative tessellation that is spread over several frames.
+//!
+//! # Overview
+//!
+//! Tessellating a large amount of geometry at once stalls the frame it happens in, and on targets without
+//! background threads (such as wasm in the browser) there is nowhere else to move that work to.
+//! The [`TimeSlicedBuilder`] queues up shapes and tessellates a bounded amount of them every time it is stepped,
+//! keeping its progress in between so the work can be resumed on the next frame.
+//!
+//! Shapes are never split, so a single shape producing more vertices than the budget still gets tessellated in one step.
+
+use std::{
+    collections::VecDeque,
+    fmt,
+};
+
+use super::mesh_builder::{
+    BevyBuffersBuilder,
+    LyonMeshBuilder,
+};
+
+use super::shapes::LyonShapeBuilder;
+
+/// Type of the shapes waiting to be tessellated by a [`TimeSlicedBuilder`].
+type PendingShape<'a> = Box<dyn FnOnce(&mut BevyBuffersBuilder) + 'a>;
+
+/// How far along a time sliced tessellation is, such as to show on a loading screen.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TessellationProgress
+{
+    pub shapes_completed: usize,
+    pub shapes_total: usize,
+    /// The number of vertices generated so far.
+    pub vertices: usize,
+}
+
+impl TessellationProgress
+{
+    /// The fraction of the shapes that are done, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f32
+    {
+        if self.shapes_total == 0 { 1.0 } else { self.shapes_completed as f32 / self.shapes_total as f32 }
+    }
+}
+
+/// Resumable builder that tessellates its shapes a few at a time, see the [module level documentation](self).
+pub struct TimeSlicedBuilder<'a>
+{
+    builder: LyonMeshBuilder,
+    pending: VecDeque<PendingShape<'a>>,
+    completed: usize,
+}
+
+impl<'a> TimeSlicedBuilder<'a>
+{
+    /// Create a new time sliced builder, adding its shapes on top of the ones already in `builder`.
+    pub fn new(builder: LyonMeshBuilder) -> Self
+    {
+        TimeSlicedBuilder {
+            builder,
+            pending: VecDeque::new(),
+            completed: 0,
+        }
+    }
+
+    /// Queues a shape to be tessellated by a later call to [`TimeSlicedBuilder::step`].
+    pub fn with(mut self, shape: impl LyonShapeBuilder + 'a) -> Self
+    {
+        self.push(shape);
+        self
+    }
+
+    /// Queues a shape to be tessellated by a later call to [`TimeSlicedBuilder::step`], without consuming the builder.
+    pub fn push(&mut self, shape: impl LyonShapeBuilder + 'a)
+    {
+        self.pending.push_back(Box::new(move |builder: &mut BevyBuffersBuilder| shape.build(builder)));
+    }
+
+    /// Tessellates queued shapes until at least `vertex_budget` vertices were generated or the queue is empty.
+    ///
+    /// At least one shape is tessellated per step, so progress is always made.
+    /// Returns whether every queued shape has been tessellated.
+    pub fn step(&mut self, vertex_budget: usize) -> bool
+    {
+        let start = self.builder.vertex_count();
+
+        while let Some(shape) = self.pending.pop_front()
+        {
+            self.builder.add_shape(shape);
+            self.completed += 1;
+
+            if self.builder.vertex_count() - start >= vertex_budget
+            {
+                break;
+            }
+        }
+
+        self.is_finished()
+    }
+
+    /// Whether every queued shape has been tessellated.
+    pub fn is_finished(&self) -> bool
+    {
+        self.pending.is_empty()
+    }
+
+    /// The number of shapes that are still waiting to be tessellated.
+    pub fn remaining(&self) -> usize
+    {
+        self.pending.len()
+    }
+
+    /// Drops every shape still waiting to be tessellated, such as when the mesh being built is no longer needed.
+    ///
+    /// Returns the number of shapes that were dropped, the ones already tessellated are kept.
+    pub fn cancel(&mut self) -> usize
+    {
+        let dropped = self.pending.len();
+        self.pending.clear();
+        dropped
+    }
+
+    /// How many of the queued shapes have been tessellated so far.
+    pub fn progress(&self) -> TessellationProgress
+    {
+        TessellationProgress {
+            shapes_completed: self.completed,
+            shapes_total: self.completed + self.pending.len(),
+            vertices: self.builder.vertex_count(),
+        }
+    }
+
+    /// Tessellates whatever is left in the queue and gives back the mesh builder, ready to be built.
+    pub fn into_builder(mut self) -> LyonMeshBuilder
+    {
+        self.step(usize::MAX);
+        self.builder
+    }
+}
+
+// Debug is implemented by hand because the pending shapes are closures.
+impl fmt::Debug for TimeSlicedBuilder<'_>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("TimeSlicedBuilder")
+            .field("builder", &self.builder)
+            .field("pending", &self.pending.len())
+            .field("completed", &self.completed)
+            .finish()
+    }
+}
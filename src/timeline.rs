@@ -0,0 +1,176 @@
+//! Timelines, a ruler above lanes of clips, such as for in-game editors and rhythm games.
+//!
+//! # Overview
+//!
+//! A [`Timeline`] lays out a span of time along X within a rect: a ruler of ticks along its top,
+//! lanes stacked below the ruler, and clips within the lanes, each with a placeholder rect for its label.
+//! Adding it through [`LyonMeshBuilder::with_timeline`] gives each of these parts a shape of its own in one builder,
+//! so a whole timeline is a single mesh whose parts can be told apart through the [`ShapeRange`]s of the builder.
+//!
+//! Clips that are partly outside of the span of time shown are cut at its edges, and clips entirely outside of it are left out.
+//!
+//! [`LyonMeshBuilder::with_timeline`]: crate::mesh_builder::LyonMeshBuilder::with_timeline
+//! [`ShapeRange`]: crate::mesh_builder::ShapeRange
+
+use std::ops::Range;
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::recording::ShapeCommand;
+use super::shapes::{
+    Corners,
+    FillRect,
+    FillRoundedRect,
+};
+
+/// A clip on a lane of a timeline, from `start` to `end` in the timeline's time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimelineClip
+{
+    pub lane: usize,
+    pub start: f32,
+    pub end: f32,
+}
+
+/// A timeline, see the [module level documentation](self).
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct Timeline
+{
+    /// The area the timeline fills, with its ruler along the top.
+    #[default(math::Rect::new(math::point(0.0, 0.0), math::size(400.0, 100.0)))]
+    pub rect: math::Rect,
+    /// The span of time shown from the left of the rect to its right, which has to be finite and not empty, the timeline has no shapes otherwise.
+    #[default(0.0..10.0)]
+    pub range: Range<f32>,
+    /// The time between two ticks of the ruler, the ruler being left out if that is more than 4096 ticks.
+    #[default = 0.5]
+    pub tick_interval: f32,
+    /// How many ticks there are from one major tick to the next, with major ticks at multiples of this many intervals.
+    #[default = 2]
+    pub major_every: usize,
+    #[default = 1.0]
+    pub tick_width: f32,
+    #[default = 12.0]
+    pub ruler_height: f32,
+    #[default = 3]
+    pub lanes: usize,
+    #[default = 20.0]
+    pub lane_height: f32,
+    #[default = 4.0]
+    pub lane_gap: f32,
+    pub clips: Vec<TimelineClip>,
+    /// How far clips are inset from the top and bottom of their lane.
+    #[default = 2.0]
+    pub clip_inset: f32,
+    #[default = 4.0]
+    pub corner_radius: f32,
+    /// The size of the placeholder rect for the label of each clip, which is shrunk to fit within short clips.
+    #[default(math::size(40.0, 8.0))]
+    pub label_size: math::Size,
+    /// The distance between the label of a clip and its left edge.
+    #[default = 4.0]
+    pub label_padding: f32,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Timeline
+{
+    /// Where a point in time is along X, or the left of the rect for every time if the range is empty.
+    pub fn time_to_x(&self, time: f32) -> f32
+    {
+        let span = self.range.end - self.range.start;
+
+        if span > 0.0 { self.rect.min_x() + (time - self.range.start) / span * self.rect.width() } else { self.rect.min_x() }
+    }
+
+    /// The area a lane takes up, from the first lane right below the ruler.
+    pub fn lane_rect(&self, lane: usize) -> math::Rect
+    {
+        let top = self.rect.max_y() - self.ruler_height - lane as f32 * (self.lane_height + self.lane_gap);
+
+        math::Rect::new(math::point(self.rect.min_x(), top - self.lane_height), math::size(self.rect.width(), self.lane_height))
+    }
+
+    /// The area a clip takes up, or nothing if it is entirely outside of the span of time shown.
+    pub fn clip_rect(&self, clip: &TimelineClip) -> Option<math::Rect>
+    {
+        let lane = self.lane_rect(clip.lane);
+        let (start, end) = (self.time_to_x(clip.start), self.time_to_x(clip.end));
+        let rect = math::Rect::new(
+            math::point(start, lane.min_y() + self.clip_inset),
+            math::size(end - start, lane.height() - self.clip_inset * 2.0),
+        );
+
+        rect.intersection(&self.rect)
+    }
+
+    /// The parts of the timeline: every tick of the ruler in order of time, then every lane, every visible clip and the label of each visible clip.
+    pub fn shapes(&self) -> Vec<ShapeCommand>
+    {
+        if !self.range.start.is_finite() || !self.range.end.is_finite() || self.range.end <= self.range.start
+        {
+            return vec![];
+        }
+
+        let options = tess::FillOptions::tolerance(self.tolerance);
+        let mut shapes = vec![];
+
+        // A tiny interval would give endless ticks, far more than could ever be told apart
+        let first = (self.range.start / self.tick_interval).ceil() as i64;
+        let last = (self.range.end / self.tick_interval).floor() as i64;
+
+        if self.tick_interval > 0.0 && last.saturating_sub(first) < 4096
+        {
+            let ruler_bottom = self.rect.max_y() - self.ruler_height;
+
+            for tick in first..=last
+            {
+                let is_major = tick.rem_euclid(self.major_every.max(1) as i64) == 0;
+                let height = if is_major { self.ruler_height } else { self.ruler_height / 2.0 };
+                let x = self.time_to_x(tick as f32 * self.tick_interval);
+
+                shapes.push(FillRect {
+                    rect: math::Rect::new(math::point(x - self.tick_width / 2.0, ruler_bottom), math::size(self.tick_width, height)),
+                    options: &options,
+                }.into());
+            }
+        }
+
+        for lane in 0..self.lanes
+        {
+            shapes.push(FillRect { rect: self.lane_rect(lane), options: &options }.into());
+        }
+
+        let clips: Vec<math::Rect> = self.clips.iter()
+            .filter_map(|clip| self.clip_rect(clip))
+            .collect();
+
+        for &rect in &clips
+        {
+            let radius = self.corner_radius.min(rect.width() / 2.0).min(rect.height() / 2.0);
+
+            shapes.push(FillRoundedRect { rect, radii: Corners::ALL.radii(radius), options: &options }.into());
+        }
+
+        for rect in clips
+        {
+            let size = math::size(
+                self.label_size.width.min(rect.width() - self.label_padding * 2.0),
+                self.label_size.height.min(rect.height()),
+            );
+
+            if size.width > 0.0 && size.height > 0.0
+            {
+                let origin = math::point(rect.min_x() + self.label_padding, rect.center().y - size.height / 2.0);
+
+                shapes.push(FillRect { rect: math::Rect::new(origin, size), options: &options }.into());
+            }
+        }
+
+        shapes
+    }
+}
@@ -0,0 +1,148 @@
+//! Visibility polygons, the area that can be seen from a point around a set of occluders.
+//!
+//! # Overview
+//!
+//! A [`VisibilityPolygon`] is made from the point to look from, the bounds the view is limited to and the outlines of any number of occluders.
+//! It is a shape like any other, so filling it through the [`LyonMeshBuilder`] gives the lit area of a 2d light
+//! or the visible area of a line of sight effect, and its outline can be used to check what is in view.
+//!
+//! Occluders block the view with their edges, so the insides of closed occluders are never visible and open ones work as walls.
+//! The polygon is recomputed from scratch every time it is built, which is cheap enough for a few hundred occluder edges per frame.
+//!
+//! [`LyonMeshBuilder`]: crate::mesh_builder::LyonMeshBuilder
+
+use smart_default::*;
+
+use lyon::{
+    path::Polygon,
+    tessellation as tess,
+};
+
+use super::math;
+use super::mesh_builder::BevyBuffersBuilder;
+use super::shapes::{
+    Contour,
+    LyonShapeBuilder,
+    LyonShapeOutline,
+};
+
+/// The area visible from a point, see the [module level documentation](self).
+#[derive(Debug, Clone, SmartDefault)]
+pub struct VisibilityPolygon<'a>
+{
+    /// The point the view is from, which should be within the bounds.
+    pub origin: math::Point,
+    /// The view doesn't reach past these, as if they were walls.
+    #[default(math::Rect::new(math::point(-100.0, -100.0), math::size(200.0, 200.0)))]
+    pub bounds: math::Rect,
+    pub occluders: Vec<Contour>,
+    #[default(&tess::FillOptions::DEFAULT)]
+    pub options: &'a tess::FillOptions,
+}
+
+impl VisibilityPolygon<'_>
+{
+    /// Adds the outline of a shape blocking the view, flattened at the tolerance of the fill options.
+    pub fn with_occluder(mut self, shape: impl LyonShapeOutline) -> Self
+    {
+        self.occluders.extend(shape.outline(self.options.tolerance));
+        self
+    }
+
+    /// Computes the corners of the visible area, counter-clockwise around the origin.
+    pub fn points(&self) -> Vec<math::Point>
+    {
+        // Just enough to see past the corners rays are aimed at
+        const EPSILON: f32 = 1e-4;
+
+        let (min, max) = (self.bounds.min(), self.bounds.max());
+        let bounds = Contour {
+            points: vec![min, math::point(max.x, min.y), max, math::point(min.x, max.y)],
+            is_closed: true,
+        };
+
+        let segments: Vec<_> = self.occluders.iter()
+            .chain(std::iter::once(&bounds))
+            .flat_map(contour_segments)
+            .collect();
+
+        let mut hits: Vec<(f32, math::Point)> = segments.iter()
+            .flat_map(|&(from, to)| vec![from, to])
+            .filter_map(|corner| (corner - self.origin).try_normalize())
+            .flat_map(|direction| {
+                // Aimed at the corner, and just to either side of it to see past it
+                [-EPSILON, 0.0, EPSILON].iter()
+                    .map(|&angle| math::Rotation::radians(angle).transform_vector(direction))
+                    .collect::<Vec<_>>()
+            })
+            .filter_map(|direction| {
+                nearest_hit(self.origin, direction, &segments)
+                    .map(|distance| (direction.y.atan2(direction.x), self.origin + direction * distance))
+            })
+            .collect();
+
+        hits.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        hits.dedup_by(|(_, a), (_, b)| (*a - *b).square_length() <= EPSILON * EPSILON);
+
+        hits.into_iter().map(|(_, point)| point).collect()
+    }
+}
+
+impl LyonShapeBuilder for VisibilityPolygon<'_>
+{
+    fn build(self, builder: &mut BevyBuffersBuilder)
+    {
+        let _ = self.try_build(builder);
+    }
+
+    fn try_build(self, builder: &mut BevyBuffersBuilder) -> tess::TessellationResult
+    {
+        tess::FillTessellator::new().tessellate_polygon(
+            Polygon { points: &self.points(), closed: true },
+            self.options,
+            builder
+        )
+    }
+}
+
+impl LyonShapeOutline for VisibilityPolygon<'_>
+{
+    fn outline(&self, _tolerance: f32) -> Vec<Contour>
+    {
+        vec![Contour { points: self.points(), is_closed: true }]
+    }
+}
+
+/// Internal utility function that gives the edges of a contour, including its closing edge if it has one.
+fn contour_segments(contour: &Contour) -> Vec<(math::Point, math::Point)>
+{
+    let mut segments: Vec<_> = contour.points.windows(2).map(|edge| (edge[0], edge[1])).collect();
+
+    if let (true, Some(&first), Some(&last)) = (contour.is_closed && contour.points.len() > 2, contour.points.first(), contour.points.last())
+    {
+        segments.push((last, first));
+    }
+
+    segments
+}
+
+/// Internal utility function that gives how far a ray travels before hitting the nearest of the segments.
+fn nearest_hit(origin: math::Point, direction: math::Vector, segments: &[(math::Point, math::Point)]) -> Option<f32>
+{
+    segments.iter()
+        .filter_map(|&(from, to)| {
+            let edge = to - from;
+            let denominator = direction.cross(edge);
+
+            if denominator.abs() <= f32::EPSILON
+            {
+                return None;
+            }
+
+            let distance = (from - origin).cross(edge) / denominator;
+            let along = (from - origin).cross(direction) / denominator;
+
+            if distance >= 0.0 && (0.0..=1.0).contains(&along) { Some(distance) } else { None }
+        })
+        .fold(None, |nearest: Option<f32>, distance| Some(nearest.map_or(distance, |nearest| nearest.min(distance))))
+}
@@ -0,0 +1,195 @@
+//! Small vector widgets, the checkboxes, radio buttons and toggles of UI kits.
+//!
+//! # Overview
+//!
+//! Each widget describes its look from its rect or center and its state, and gives its parts as [`ShapeCommand`]s from [`Widget::shapes`].
+//! Adding a widget through [`LyonMeshBuilder::with_widget`] gives each of its parts a shape of its own,
+//! so they can be told apart through the [`ShapeRange`]s of the builder, such as to color them.
+//!
+//! The state of a widget is a fraction rather than a flag, from `0.0` for off to `1.0` for on,
+//! so that easing it over a few frames animates the widget: the check mark of a [`Checkbox`] draws itself in,
+//! the dot of a [`RadioButton`] grows and the knob of a [`Toggle`] slides across.
+//!
+//! [`LyonMeshBuilder::with_widget`]: crate::mesh_builder::LyonMeshBuilder::with_widget
+//! [`ShapeRange`]: crate::mesh_builder::ShapeRange
+
+use smart_default::*;
+
+use lyon::tessellation as tess;
+
+use super::math;
+use super::recording::ShapeCommand;
+use super::shapes::{
+    Corners,
+    FillCircle,
+    FillRoundedRect,
+    StrokeCircle,
+    StrokePolyline,
+    StrokeRoundedRect,
+};
+
+/// A widget made of a few shapes, see the [module level documentation](self).
+pub trait Widget
+{
+    /// The parts of the widget, from the bottom up.
+    fn shapes(&self) -> Vec<ShapeCommand>;
+}
+
+/// A checkbox, a rounded box with a check mark drawn into it as it gets checked.
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct Checkbox
+{
+    #[default(math::Rect::new(math::point(0.0, 0.0), math::size(16.0, 16.0)))]
+    pub rect: math::Rect,
+    /// How much of the check mark is drawn, from `0.0` for unchecked to `1.0` for checked.
+    pub checked: f32,
+    #[default = 3.0]
+    pub corner_radius: f32,
+    #[default = 1.5]
+    pub border_width: f32,
+    #[default = 2.0]
+    pub check_width: f32,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Checkbox
+{
+    /// The points of the whole check mark, from its short stroke down to its long stroke up.
+    pub fn check_mark(&self) -> [math::Point; 3]
+    {
+        let at = |x: f32, y: f32| math::point(self.rect.min_x() + x * self.rect.width(), self.rect.min_y() + y * self.rect.height());
+
+        [at(0.22, 0.52), at(0.42, 0.3), at(0.78, 0.72)]
+    }
+}
+
+impl Widget for Checkbox
+{
+    /// The border of the box, then the check mark if any of it is drawn.
+    fn shapes(&self) -> Vec<ShapeCommand>
+    {
+        let border = tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.border_width);
+        let check = tess::StrokeOptions::tolerance(self.tolerance)
+            .with_line_width(self.check_width)
+            .with_line_join(tess::LineJoin::Round)
+            .with_line_cap(tess::LineCap::Round);
+
+        let radius = self.corner_radius.min(self.rect.width() / 2.0).min(self.rect.height() / 2.0);
+        let mut shapes = vec![StrokeRoundedRect { rect: self.rect, radii: Corners::ALL.radii(radius), options: &border }.into()];
+
+        if self.checked > 0.0
+        {
+            let points = partial_polyline(&self.check_mark(), self.checked.min(1.0));
+
+            shapes.push(StrokePolyline { points, is_closed: false, options: &check }.into());
+        }
+
+        shapes
+    }
+}
+
+/// A radio button, a ring with a dot growing in it as it gets selected.
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct RadioButton
+{
+    pub center: math::Point,
+    #[default = 8.0]
+    pub radius: f32,
+    /// How grown the dot is, from `0.0` for unselected to `1.0` for selected.
+    pub selected: f32,
+    #[default = 1.5]
+    pub ring_width: f32,
+    /// The radius of the fully grown dot, as a fraction of the radius of the ring.
+    #[default = 0.5]
+    pub dot_scale: f32,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Widget for RadioButton
+{
+    /// The ring, then the dot if it has grown at all.
+    fn shapes(&self) -> Vec<ShapeCommand>
+    {
+        let ring = tess::StrokeOptions::tolerance(self.tolerance).with_line_width(self.ring_width);
+        let dot = tess::FillOptions::tolerance(self.tolerance);
+
+        let mut shapes = vec![StrokeCircle { center: self.center, radius: self.radius, options: &ring }.into()];
+
+        if self.selected > 0.0
+        {
+            let radius = self.radius * self.dot_scale * self.selected.min(1.0);
+
+            shapes.push(FillCircle { center: self.center, radius, options: &dot }.into());
+        }
+
+        shapes
+    }
+}
+
+/// A toggle switch, a pill with a round knob sliding from its left end to its right end as it gets switched on.
+#[derive(Debug, Clone, Copy, PartialEq, SmartDefault)]
+pub struct Toggle
+{
+    #[default(math::Rect::new(math::point(0.0, 0.0), math::size(32.0, 16.0)))]
+    pub rect: math::Rect,
+    /// How far the knob has slid, from `0.0` for off to `1.0` for on.
+    pub on: f32,
+    /// The distance between the knob and the edge of the pill.
+    #[default = 2.0]
+    pub knob_inset: f32,
+    #[default = 0.1]
+    pub tolerance: f32,
+}
+
+impl Toggle
+{
+    /// The center of the knob.
+    pub fn knob_center(&self) -> math::Point
+    {
+        let radius = self.rect.height() / 2.0;
+        let (left, right) = (self.rect.min_x() + radius, self.rect.max_x() - radius);
+
+        math::point(left + (right - left) * self.on.max(0.0).min(1.0), self.rect.center().y)
+    }
+}
+
+impl Widget for Toggle
+{
+    /// The pill, then the knob.
+    fn shapes(&self) -> Vec<ShapeCommand>
+    {
+        let options = tess::FillOptions::tolerance(self.tolerance);
+        let radius = self.rect.height() / 2.0;
+
+        vec![
+            FillRoundedRect { rect: self.rect, radii: Corners::ALL.radii(radius), options: &options }.into(),
+            FillCircle { center: self.knob_center(), radius: (radius - self.knob_inset).max(0.0), options: &options }.into(),
+        ]
+    }
+}
+
+/// Internal utility function that cuts a polyline short, keeping a fraction of its length from its start.
+fn partial_polyline(points: &[math::Point], fraction: f32) -> Vec<math::Point>
+{
+    let total: f32 = points.windows(2).map(|segment| (segment[1] - segment[0]).length()).sum();
+    let mut remaining = total * fraction;
+    let mut partial = vec![points[0]];
+
+    for segment in points.windows(2)
+    {
+        let length = (segment[1] - segment[0]).length();
+
+        if remaining < length
+        {
+            partial.push(segment[0].lerp(segment[1], remaining / length));
+            break;
+        }
+
+        partial.push(segment[1]);
+        remaining -= length;
+    }
+
+    partial
+}